@@ -76,4 +76,11 @@ impl LockfilePort for LeagueLockfileProvider {
 
         Ok(LockfileData { port, password })
     }
+
+    fn install_dir(&self) -> anyhow::Result<PathBuf> {
+        let path = self.find_lockfile_path()?;
+        path.parent()
+            .map(|p| p.to_path_buf())
+            .ok_or_else(|| anyhow::anyhow!("lockfile path has no parent"))
+    }
 }
@@ -41,4 +41,26 @@ impl DataDragonApiPort for DataDragonApiAdapter {
         resp.error_for_status_ref()?;
         Ok(resp.json::<Value>().await?)
     }
+
+    async fn get_items_json(&self, version: &str) -> Result<Value> {
+        let url = format!(
+            "https://ddragon.leagueoflegends.com/cdn/{}/data/en_US/item.json",
+            version
+        );
+
+        let resp = self.client.get(&url).send().await?;
+        resp.error_for_status_ref()?;
+        Ok(resp.json::<Value>().await?)
+    }
+
+    async fn get_runes_json(&self, version: &str) -> Result<Value> {
+        let url = format!(
+            "https://ddragon.leagueoflegends.com/cdn/{}/data/en_US/runesReforged.json",
+            version
+        );
+
+        let resp = self.client.get(&url).send().await?;
+        resp.error_for_status_ref()?;
+        Ok(resp.json::<Value>().await?)
+    }
 }
@@ -10,9 +10,12 @@ use crate::domain::ports::{ChampionResolverPort, DataDragonApiPort};
 
 #[derive(Debug, Deserialize)]
 struct ChampionJsonData {
+    id: String,
     #[serde(rename = "key")]
     key: String,
     name: String,
+    #[serde(default)]
+    tags: Vec<String>,
 }
 
 
@@ -21,6 +24,8 @@ pub struct DataDragonChampionResolver {
     version: Arc<RwLock<Option<String>>>,
     name_to_id: Arc<RwLock<HashMap<String, i32>>>,
     id_to_name: Arc<RwLock<HashMap<i32, String>>>,
+    id_to_key: Arc<RwLock<HashMap<i32, String>>>,
+    id_to_tags: Arc<RwLock<HashMap<i32, Vec<String>>>>,
     champions_cache: Arc<RwLock<Option<Vec<(String, i32)>>>>,
 }
 
@@ -31,6 +36,8 @@ impl Clone for DataDragonChampionResolver {
             version: Arc::clone(&self.version),
             name_to_id: Arc::clone(&self.name_to_id),
             id_to_name: Arc::clone(&self.id_to_name),
+            id_to_key: Arc::clone(&self.id_to_key),
+            id_to_tags: Arc::clone(&self.id_to_tags),
             champions_cache: Arc::clone(&self.champions_cache),
         }
     }
@@ -43,6 +50,8 @@ impl DataDragonChampionResolver {
             version: Arc::new(RwLock::new(None)),
             name_to_id: Arc::new(RwLock::new(HashMap::new())),
             id_to_name: Arc::new(RwLock::new(HashMap::new())),
+            id_to_key: Arc::new(RwLock::new(HashMap::new())),
+            id_to_tags: Arc::new(RwLock::new(HashMap::new())),
             champions_cache: Arc::new(RwLock::new(None)),
         }
     }
@@ -87,6 +96,8 @@ impl DataDragonChampionResolver {
         };
 
         let mut output = Vec::new();
+        let mut keys = HashMap::new();
+        let mut tags = HashMap::new();
 
         let Some(map) = json.get("data").and_then(|v| v.as_object()) else {
             info!("⚠️ champions.json missing `data` field");
@@ -110,9 +121,14 @@ impl DataDragonChampionResolver {
                 }
             };
 
+            keys.insert(id, parsed.id);
+            tags.insert(id, parsed.tags);
             output.push((parsed.name, id));
         }
 
+        *self.id_to_key.write().await = keys;
+        *self.id_to_tags.write().await = tags;
+
         let mut guard = self.champions_cache.write().await;
         *guard = Some(output.clone());
 
@@ -164,4 +180,32 @@ impl ChampionResolverPort for DataDragonChampionResolver {
         let guard = self.id_to_name.read().await;
         guard.get(&champ_id).cloned()
     }
+
+    async fn resolve_key(&self, champ_id: i32) -> Option<String> {
+        {
+            let guard = self.id_to_key.read().await;
+            if !guard.is_empty() {
+                return guard.get(&champ_id).cloned();
+            }
+        }
+
+        self.refresh_cache().await;
+
+        let guard = self.id_to_key.read().await;
+        guard.get(&champ_id).cloned()
+    }
+
+    async fn resolve_tags(&self, champ_id: i32) -> Vec<String> {
+        {
+            let guard = self.id_to_tags.read().await;
+            if !guard.is_empty() {
+                return guard.get(&champ_id).cloned().unwrap_or_default();
+            }
+        }
+
+        self.refresh_cache().await;
+
+        let guard = self.id_to_tags.read().await;
+        guard.get(&champ_id).cloned().unwrap_or_default()
+    }
 }
@@ -0,0 +1,52 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+
+use crate::domain::ports::{DataDragonApiPort, ItemCatalogPort};
+
+pub struct DataDragonItemCatalog {
+    api: Arc<dyn DataDragonApiPort>,
+    items_cache: RwLock<Option<HashSet<i32>>>,
+}
+
+impl DataDragonItemCatalog {
+    pub fn new(api: Arc<dyn DataDragonApiPort>) -> Self {
+        Self {
+            api,
+            items_cache: RwLock::new(None),
+        }
+    }
+}
+
+#[async_trait]
+impl ItemCatalogPort for DataDragonItemCatalog {
+    async fn item_ids(&self) -> Result<HashSet<i32>> {
+        {
+            let guard = self.items_cache.read().await;
+            if let Some(ids) = &*guard {
+                return Ok(ids.clone());
+            }
+        }
+
+        let versions = self.api.get_versions().await?;
+        let version = versions
+            .first()
+            .ok_or_else(|| anyhow!("Data Dragon returned no versions"))?;
+
+        let json = self.api.get_items_json(version).await?;
+        let map = json
+            .get("data")
+            .and_then(|v| v.as_object())
+            .ok_or_else(|| anyhow!("item.json missing `data` field"))?;
+
+        let ids: HashSet<i32> = map.keys().filter_map(|k| k.parse().ok()).collect();
+
+        let mut guard = self.items_cache.write().await;
+        *guard = Some(ids.clone());
+
+        Ok(ids)
+    }
+}
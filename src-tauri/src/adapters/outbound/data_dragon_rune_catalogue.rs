@@ -0,0 +1,84 @@
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::domain::ports::{DataDragonApiPort, RuneCataloguePort};
+use crate::domain::rune_catalogue::{RuneCatalogue, RuneTree};
+
+/// Rune trees from Data Dragon's `runesReforged.json`, loaded once.
+pub struct DataDragonRuneCatalogue {
+    api: Arc<dyn DataDragonApiPort>,
+    cache: RwLock<Option<RuneCatalogue>>,
+}
+
+impl DataDragonRuneCatalogue {
+    pub fn new(api: Arc<dyn DataDragonApiPort>) -> Self {
+        Self {
+            api,
+            cache: RwLock::new(None),
+        }
+    }
+
+    fn parse_tree(style: &Value) -> Option<RuneTree> {
+        let rows = style
+            .get("slots")?
+            .as_array()?
+            .iter()
+            .map(|slot| {
+                slot.get("runes")
+                    .and_then(|r| r.as_array())
+                    .map(|runes| {
+                        runes
+                            .iter()
+                            .filter_map(|r| r.get("id")?.as_i64().map(|id| id as i32))
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        Some(RuneTree {
+            id: style.get("id")?.as_i64()? as i32,
+            name: style
+                .get("name")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .into(),
+            rows,
+        })
+    }
+}
+
+#[async_trait]
+impl RuneCataloguePort for DataDragonRuneCatalogue {
+    async fn catalogue(&self) -> Result<RuneCatalogue> {
+        {
+            let guard = self.cache.read().await;
+            if let Some(c) = &*guard {
+                return Ok(c.clone());
+            }
+        }
+
+        let versions = self.api.get_versions().await?;
+        let version = versions
+            .first()
+            .ok_or_else(|| anyhow!("Data Dragon returned no versions"))?;
+
+        let json = self.api.get_runes_json(version).await?;
+        let styles = json
+            .as_array()
+            .ok_or_else(|| anyhow!("runesReforged.json is not an array"))?;
+
+        let catalogue = RuneCatalogue {
+            trees: styles.iter().filter_map(Self::parse_tree).collect(),
+        };
+
+        let mut guard = self.cache.write().await;
+        *guard = Some(catalogue.clone());
+
+        Ok(catalogue)
+    }
+}
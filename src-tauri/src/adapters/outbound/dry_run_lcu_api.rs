@@ -0,0 +1,145 @@
+use crate::domain::ports::LcuApiPort;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use log::info;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tauri::Wry;
+use tauri_plugin_store::Store;
+
+/// How many recorded intents are kept; older ones are dropped first.
+const MAX_INTENTS: usize = 500;
+/// Store key of the dry-run switch, so it survives a restart.
+const ENABLED_KEY: &str = "dryRun";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedIntent {
+    pub method: String,
+    pub path: String,
+    pub body: Option<Value>,
+    /// When the request was last made.
+    pub at: DateTime<Utc>,
+    /// How many times in a row it was made; repeats are collapsed into one entry.
+    pub count: u32,
+}
+
+/// Wraps the LCU API for features. While dry run is on, reads go through but
+/// post/put/patch/delete are only recorded and reported as successful.
+pub struct DryRunLcuApi {
+    inner: Arc<dyn LcuApiPort>,
+    store: Arc<Store<Wry>>,
+    enabled: AtomicBool,
+    intents: Mutex<VecDeque<RecordedIntent>>,
+}
+
+impl DryRunLcuApi {
+    pub fn new(inner: Arc<dyn LcuApiPort>, store: Arc<Store<Wry>>) -> Self {
+        let enabled = store
+            .get(ENABLED_KEY)
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        if enabled {
+            info!("[DryRun] Enabled from last session");
+        }
+
+        Self {
+            inner,
+            store,
+            enabled: AtomicBool::new(enabled),
+            intents: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        info!("[DryRun] {}", if enabled { "Enabled" } else { "Disabled" });
+        self.enabled.store(enabled, Ordering::Relaxed);
+        self.store.set(ENABLED_KEY, json!(enabled));
+    }
+
+    /// Recorded intents, oldest first.
+    pub fn intents(&self) -> Vec<RecordedIntent> {
+        self.intents.lock().unwrap().iter().cloned().collect()
+    }
+
+    pub fn clear(&self) {
+        self.intents.lock().unwrap().clear();
+    }
+
+    /// Records the request when dry run is on; returns whether it was intercepted.
+    fn intercept(&self, method: &str, path: &str, body: Option<&Value>) -> bool {
+        if !self.is_enabled() {
+            return false;
+        }
+
+        info!("[DryRun] {} {} {:?}", method, path, body);
+
+        let mut intents = self.intents.lock().unwrap();
+
+        // Nothing reaches the client, so features repeat the same request on
+        // every update; keep one entry for the run.
+        if let Some(last) = intents
+            .back_mut()
+            .filter(|last| last.method == method && last.path == path && last.body.as_ref() == body)
+        {
+            last.at = Utc::now();
+            last.count += 1;
+            return true;
+        }
+
+        if intents.len() >= MAX_INTENTS {
+            intents.pop_front();
+        }
+        intents.push_back(RecordedIntent {
+            method: method.into(),
+            path: path.into(),
+            body: body.cloned(),
+            at: Utc::now(),
+            count: 1,
+        });
+
+        true
+    }
+}
+
+#[async_trait]
+impl LcuApiPort for DryRunLcuApi {
+    async fn get(&self, path: &str) -> anyhow::Result<Value> {
+        self.inner.get(path).await
+    }
+
+    async fn post(&self, path: &str, body: Option<&Value>) -> anyhow::Result<Option<Value>> {
+        if self.intercept("POST", path, body) {
+            return Ok(None);
+        }
+        self.inner.post(path, body).await
+    }
+
+    async fn put(&self, path: &str, body: Option<&Value>) -> anyhow::Result<Option<Value>> {
+        if self.intercept("PUT", path, body) {
+            return Ok(None);
+        }
+        self.inner.put(path, body).await
+    }
+
+    async fn patch(&self, path: &str, body: Option<&Value>) -> anyhow::Result<Option<Value>> {
+        if self.intercept("PATCH", path, body) {
+            return Ok(None);
+        }
+        self.inner.patch(path, body).await
+    }
+
+    async fn delete(&self, path: &str) -> anyhow::Result<Option<Value>> {
+        if self.intercept("DELETE", path, None) {
+            return Ok(None);
+        }
+        self.inner.delete(path).await
+    }
+}
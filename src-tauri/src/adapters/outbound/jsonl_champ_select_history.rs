@@ -0,0 +1,79 @@
+use anyhow::Result;
+use log::warn;
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::domain::champ_select::history::{ChampSelectRecord, HistoryQuery};
+use crate::domain::ports::ChampSelectHistoryPort;
+
+const FILE_NAME: &str = "champ_select_history.jsonl";
+
+/// Keeps champ select records one JSON object per line in the app data
+/// directory, apart from the settings store.
+pub struct JsonlChampSelectHistory {
+    path: PathBuf,
+    /// Serializes appends so concurrent records never interleave.
+    write_lock: Mutex<()>,
+}
+
+impl JsonlChampSelectHistory {
+    pub fn new(data_dir: PathBuf) -> Self {
+        Self {
+            path: data_dir.join(FILE_NAME),
+            write_lock: Mutex::new(()),
+        }
+    }
+}
+
+impl ChampSelectHistoryPort for JsonlChampSelectHistory {
+    fn append(&self, record: &ChampSelectRecord) -> Result<()> {
+        let _guard = self.write_lock.lock().unwrap();
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut line = serde_json::to_string(record)?;
+        line.push('\n');
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        file.write_all(line.as_bytes())?;
+
+        Ok(())
+    }
+
+    fn query(&self, query: &HistoryQuery) -> Result<Vec<ChampSelectRecord>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let file = fs::File::open(&self.path)?;
+        let mut out = Vec::new();
+
+        for (n, line) in BufReader::new(file).lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            // A half-written last line after a crash should not hide the rest.
+            match serde_json::from_str::<ChampSelectRecord>(&line) {
+                Ok(r) if query.matches(&r) => out.push(r),
+                Ok(_) => {}
+                Err(e) => warn!("[History] Skipping line {}: {}", n + 1, e),
+            }
+        }
+
+        out.reverse();
+        if let Some(limit) = query.limit {
+            out.truncate(limit);
+        }
+
+        Ok(out)
+    }
+}
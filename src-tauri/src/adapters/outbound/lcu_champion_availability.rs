@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use log::{info, warn};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+use crate::domain::champ_select::availability::ChampionAvailability;
+use crate::domain::champ_select::models::ChampSelectSessionPayload;
+use crate::domain::ports::{ChampionAvailabilityPort, LcuApiPort};
+
+pub struct LcuChampionAvailability {
+    api: Arc<dyn LcuApiPort>,
+    /// Last complete result, keyed by game id.
+    cache: Mutex<Option<(i64, ChampionAvailability)>>,
+}
+
+impl LcuChampionAvailability {
+    pub fn new(api: Arc<dyn LcuApiPort>) -> Self {
+        Self {
+            api,
+            cache: Mutex::new(None),
+        }
+    }
+
+    async fn id_set(&self, path: &str) -> Option<HashSet<i32>> {
+        match self.api.get(path).await {
+            Ok(v) => Some(
+                v.as_array()?
+                    .iter()
+                    .filter_map(|id| id.as_i64().map(|id| id as i32))
+                    .collect(),
+            ),
+            Err(e) => {
+                warn!("[Availability] ⚠ GET {} failed: {}", path, e);
+                None
+            }
+        }
+    }
+
+    async fn owned(&self) -> Option<HashSet<i32>> {
+        let path = "/lol-champions/v1/owned-champions-minimal";
+        let list = match self.api.get(path).await {
+            Ok(v) => v,
+            Err(e) => {
+                warn!("[Availability] ⚠ GET {} failed: {}", path, e);
+                return None;
+            }
+        };
+
+        Some(
+            list.as_array()?
+                .iter()
+                .filter(|c| owned_or_rented(c))
+                .filter_map(|c| c.get("id")?.as_i64().map(|id| id as i32))
+                .collect(),
+        )
+    }
+}
+
+fn owned_or_rented(entry: &Value) -> bool {
+    let flag = |ptr: &str| {
+        entry
+            .pointer(ptr)
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+    };
+    flag("/ownership/owned") || flag("/ownership/rental/rented")
+}
+
+#[async_trait]
+impl ChampionAvailabilityPort for LcuChampionAvailability {
+    async fn availability(&self, session: &ChampSelectSessionPayload) -> ChampionAvailability {
+        if let Some((game_id, cached)) = &*self.cache.lock().unwrap() {
+            if *game_id == session.gameId {
+                return cached.clone();
+            }
+        }
+
+        let availability = ChampionAvailability {
+            pickable: self
+                .id_set("/lol-champ-select/v1/pickable-champion-ids")
+                .await,
+            bannable: self
+                .id_set("/lol-champ-select/v1/bannable-champion-ids")
+                .await,
+            owned: self.owned().await,
+        };
+
+        if availability.is_complete() {
+            info!(
+                "[Availability] Cached for game {}: {} pickable, {} bannable, {} owned",
+                session.gameId,
+                availability.pickable.as_ref().map_or(0, |s| s.len()),
+                availability.bannable.as_ref().map_or(0, |s| s.len()),
+                availability.owned.as_ref().map_or(0, |s| s.len()),
+            );
+            *self.cache.lock().unwrap() = Some((session.gameId, availability.clone()));
+        }
+
+        availability
+    }
+}
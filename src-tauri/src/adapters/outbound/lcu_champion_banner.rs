@@ -3,16 +3,29 @@ use std::sync::Arc;
 
 use crate::application::features::auto_pick_ban::ban_pick_request::BanPickRequest;
 use crate::domain::champ_select::models::{ChampSelectSessionPayload, CsAction};
-use crate::domain::ports::{BanStrategy, BannerPort, LcuApiPort};
+use crate::domain::champ_select::session_control::SessionControl;
+use crate::domain::ports::{BanStrategy, BannerPort, ChampionAvailabilityPort, LcuApiPort};
 
 pub struct ChampionBanner<S> {
     api: Arc<dyn LcuApiPort>,
+    availability: Arc<dyn ChampionAvailabilityPort>,
     strategy: Arc<S>,
+    control: Arc<SessionControl>,
 }
 
 impl<S> ChampionBanner<S> {
-    pub fn new(api: Arc<dyn LcuApiPort>, strategy: Arc<S>) -> Self {
-        Self { api, strategy }
+    pub fn new(
+        api: Arc<dyn LcuApiPort>,
+        availability: Arc<dyn ChampionAvailabilityPort>,
+        strategy: Arc<S>,
+        control: Arc<SessionControl>,
+    ) -> Self {
+        Self {
+            api,
+            availability,
+            strategy,
+            control,
+        }
     }
 }
 
@@ -22,7 +35,13 @@ where
     S: BanStrategy + 'static,
 {
     async fn ban(&self, session: &ChampSelectSessionPayload, action: &CsAction, role: &str) {
-        let req = BanPickRequest::from_session(session, role);
+        if self.control.is_user_controlled(session.gameId) {
+            info!("[Ban] Session is user-controlled, skipping");
+            return;
+        }
+
+        let availability = self.availability.availability(session).await;
+        let req = BanPickRequest::from_session(session, role).with_availability(availability);
         info!("{:?}", req);
 
         let decision = self.strategy.decide(&req).await;
@@ -42,6 +61,8 @@ where
             "completed": true
         });
 
+        self.control.record(session.gameId, action.id, cid);
+
         let _ = self
             .api
             .patch(
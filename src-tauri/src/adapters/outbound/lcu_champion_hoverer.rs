@@ -3,17 +3,30 @@ use std::sync::Arc;
 
 use crate::application::features::auto_pick_ban::ban_pick_request::BanPickRequest;
 use crate::domain::champ_select::models::{ChampSelectSessionPayload, CsAction};
+use crate::domain::champ_select::session_control::SessionControl;
 use crate::domain::ports::LcuApiPort;
-use crate::domain::ports::{HovererPort, PickingStrategy};
+use crate::domain::ports::{ChampionAvailabilityPort, HovererPort, PickingStrategy};
 
 pub struct ChampionHoverer<S> {
     api: Arc<dyn LcuApiPort>,
+    availability: Arc<dyn ChampionAvailabilityPort>,
     strategy: Arc<S>,
+    control: Arc<SessionControl>,
 }
 
 impl<S> ChampionHoverer<S> {
-    pub fn new(api: Arc<dyn LcuApiPort>, strategy: Arc<S>) -> Self {
-        Self { api, strategy }
+    pub fn new(
+        api: Arc<dyn LcuApiPort>,
+        availability: Arc<dyn ChampionAvailabilityPort>,
+        strategy: Arc<S>,
+        control: Arc<SessionControl>,
+    ) -> Self {
+        Self {
+            api,
+            availability,
+            strategy,
+            control,
+        }
     }
 }
 
@@ -23,12 +36,8 @@ where
     S: PickingStrategy + 'static,
 {
     async fn hover(&self, session: &ChampSelectSessionPayload, role: &str) {
-        let req = BanPickRequest::from_session(session, role);
-        info!("{:?}", req);
-
-        let decision = self.strategy.decide(&req).await;
-        if !decision.has_choice() {
-            info!("No decision for {:?}", req);
+        if self.control.is_user_controlled(session.gameId) {
+            info!("[Hover] Session is user-controlled, skipping");
             return;
         }
 
@@ -42,6 +51,41 @@ where
             return;
         }
 
+        let availability = self.availability.availability(session).await;
+        let req = BanPickRequest::from_session(session, role).with_availability(availability);
+        info!("{:?}", req);
+
+        let current = action.championId;
+        if current != 0 {
+            if !self
+                .control
+                .was_set_by_bot(session.gameId, action.id, current)
+            {
+                info!(
+                    "[Hover] Champion {} was hovered manually, leaving it",
+                    current
+                );
+                return;
+            }
+
+            match req.pick_blocker(current as i32) {
+                Some(reason) => info!(
+                    "[Hover] Current hover {} is {}, re-deciding",
+                    current, reason
+                ),
+                None => {
+                    info!("[Hover] Current hover {} is still valid", current);
+                    return;
+                }
+            }
+        }
+
+        let decision = self.strategy.decide(&req).await;
+        if !decision.has_choice() {
+            info!("No decision for {:?}", req);
+            return;
+        }
+
         // FIXED: convert i32 → i64
         let cid = decision.champion_id() as i64;
 
@@ -57,6 +101,9 @@ where
             "championId": cid
         });
 
+        // Recorded before the PATCH so the session update it triggers is recognised as ours.
+        self.control.record(session.gameId, action.id, cid);
+
         let _ = self
             .api
             .patch(
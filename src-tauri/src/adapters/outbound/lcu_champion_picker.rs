@@ -3,16 +3,29 @@ use std::sync::Arc;
 
 use crate::application::features::auto_pick_ban::ban_pick_request::BanPickRequest;
 use crate::domain::champ_select::models::{ChampSelectSessionPayload, CsAction};
-use crate::domain::ports::{LcuApiPort, PickerPort, PickingStrategy};
+use crate::domain::champ_select::session_control::SessionControl;
+use crate::domain::ports::{ChampionAvailabilityPort, LcuApiPort, PickerPort, PickingStrategy};
 
 pub struct ChampionPicker<S> {
     api: Arc<dyn LcuApiPort>,
+    availability: Arc<dyn ChampionAvailabilityPort>,
     strategy: Arc<S>,
+    control: Arc<SessionControl>,
 }
 
 impl<S> ChampionPicker<S> {
-    pub fn new(api: Arc<dyn LcuApiPort>, strategy: Arc<S>) -> Self {
-        Self { api, strategy }
+    pub fn new(
+        api: Arc<dyn LcuApiPort>,
+        availability: Arc<dyn ChampionAvailabilityPort>,
+        strategy: Arc<S>,
+        control: Arc<SessionControl>,
+    ) -> Self {
+        Self {
+            api,
+            availability,
+            strategy,
+            control,
+        }
     }
 }
 
@@ -22,7 +35,13 @@ where
     S: PickingStrategy + 'static,
 {
     async fn pick(&self, session: &ChampSelectSessionPayload, action: &CsAction, role: &str) {
-        let req = BanPickRequest::from_session(session, role);
+        if self.control.is_user_controlled(session.gameId) {
+            info!("[Pick] Session is user-controlled, skipping");
+            return;
+        }
+
+        let availability = self.availability.availability(session).await;
+        let req = BanPickRequest::from_session(session, role).with_availability(availability);
         info!("{:?}", req);
 
         let decision = self.strategy.decide(&req).await;
@@ -45,6 +64,8 @@ where
             "completed": true
         });
 
+        self.control.record(session.gameId, action.id, cid);
+
         let _ = self
             .api
             .patch(
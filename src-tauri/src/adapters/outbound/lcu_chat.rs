@@ -0,0 +1,48 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+
+use crate::domain::ports::{ChatPort, LcuApiPort};
+
+pub struct LcuChat {
+    api: Arc<dyn LcuApiPort>,
+}
+
+impl LcuChat {
+    pub fn new(api: Arc<dyn LcuApiPort>) -> Self {
+        Self { api }
+    }
+}
+
+#[async_trait]
+impl ChatPort for LcuChat {
+    async fn champ_select_conversation(&self) -> Result<Option<String>> {
+        let conversations = self.api.get("/lol-chat/v1/conversations").await?;
+
+        let id = conversations
+            .as_array()
+            .into_iter()
+            .flatten()
+            .find(|c| c.get("type").and_then(|v| v.as_str()) == Some("championSelect"))
+            .and_then(|c| c.get("id")?.as_str().map(|s| s.to_string()));
+
+        Ok(id)
+    }
+
+    async fn send(&self, conversation_id: &str, body: &str) -> Result<()> {
+        let payload = json!({
+            "body": body,
+            "type": "groupchat",
+        });
+
+        self.api
+            .post(
+                &format!("/lol-chat/v1/conversations/{}/messages", conversation_id),
+                Some(&payload),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+use crate::domain::item_set::ItemSet;
+use crate::domain::ports::{ItemSetWriterPort, LcuApiPort};
+
+/// uid prefix marking sets pushed by Overseer, so stale ones can be replaced.
+const UID_PREFIX: &str = "overseer-";
+
+/// Pushes item sets to the client through `/lol-item-sets`.
+pub struct LcuItemSetWriter {
+    api: Arc<dyn LcuApiPort>,
+}
+
+impl LcuItemSetWriter {
+    pub fn new(api: Arc<dyn LcuApiPort>) -> Self {
+        Self { api }
+    }
+
+    async fn summoner_id(&self) -> Result<i64> {
+        let me = self.api.get("/lol-summoner/v1/current-summoner").await?;
+        me.get("summonerId")
+            .and_then(|v| v.as_i64())
+            .ok_or_else(|| anyhow!("current-summoner has no summonerId"))
+    }
+
+    fn to_lcu(champion_id: i32, set: &ItemSet) -> Value {
+        let blocks: Vec<_> = set
+            .blocks
+            .iter()
+            .map(|b| {
+                json!({
+                    "type": b.title,
+                    "items": b.items.iter().map(|id| json!({
+                        "id": id.to_string(),
+                        "count": 1
+                    })).collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        json!({
+            "uid": format!("{}{}-{}", UID_PREFIX, champion_id, set.id),
+            "title": set.title,
+            "type": "custom",
+            "map": "any",
+            "mode": "any",
+            "sortrank": 0,
+            "startedFrom": "blank",
+            "preferredItemSlots": [],
+            "associatedChampions": [champion_id],
+            "associatedMaps": set.map_ids,
+            "blocks": blocks
+        })
+    }
+}
+
+#[async_trait]
+impl ItemSetWriterPort for LcuItemSetWriter {
+    async fn write(&self, champion_id: i32, _champion_key: &str, sets: &[ItemSet]) -> Result<()> {
+        let summoner = self.summoner_id().await?;
+        let path = format!("/lol-item-sets/v1/item-sets/{}/sets", summoner);
+
+        let mut current = self.api.get(&path).await?;
+        let ours = format!("{}{}-", UID_PREFIX, champion_id);
+
+        let mut item_sets: Vec<Value> = current
+            .get("itemSets")
+            .and_then(|v| v.as_array())
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|s| {
+                !s.get("uid")
+                    .and_then(|v| v.as_str())
+                    .map(|uid| uid.starts_with(&ours))
+                    .unwrap_or(false)
+            })
+            .collect();
+
+        item_sets.extend(sets.iter().map(|s| Self::to_lcu(champion_id, s)));
+
+        current["itemSets"] = Value::Array(item_sets);
+        self.api.put(&path, Some(&current)).await?;
+
+        log::info!(
+            "ItemSets: pushed {} set(s) for champion {}",
+            sets.len(),
+            champion_id
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::sync::Arc;
+
+use crate::domain::match_history::MatchRecord;
+use crate::domain::ports::{LcuApiPort, MatchHistoryPort};
+use crate::domain::role_resolver::parse_role;
+
+/// Games shorter than this are remakes and say nothing about the champion.
+const MIN_GAME_SECONDS: i64 = 300;
+
+/// Reads the current summoner's games from the client's match history.
+pub struct LcuMatchHistory {
+    api: Arc<dyn LcuApiPort>,
+}
+
+impl LcuMatchHistory {
+    pub fn new(api: Arc<dyn LcuApiPort>) -> Self {
+        Self { api }
+    }
+
+    async fn puuid(&self) -> Result<String> {
+        let me = self.api.get("/lol-summoner/v1/current-summoner").await?;
+        me.get("puuid")
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .ok_or_else(|| anyhow!("current-summoner has no puuid"))
+    }
+}
+
+/// Lane and role as the match history reports them, e.g. BOTTOM + DUO_SUPPORT.
+fn role_of(timeline: &Value) -> Option<String> {
+    let lane = timeline.get("lane")?.as_str()?;
+    let role = timeline.get("role").and_then(|v| v.as_str()).unwrap_or("");
+
+    match (lane, role) {
+        ("BOTTOM" | "BOT", "DUO_SUPPORT" | "SUPPORT") => Some("support".into()),
+        _ => parse_role(lane),
+    }
+}
+
+/// The line of `puuid` in a game, or the only participant when the game
+/// comes from the current-summoner list.
+fn parse_game(game: &Value, puuid: Option<&str>) -> Option<MatchRecord> {
+    if game.get("gameDuration")?.as_i64()? < MIN_GAME_SECONDS {
+        return None;
+    }
+
+    let participants = game.get("participants")?.as_array()?;
+
+    let participant = match puuid {
+        Some(puuid) => {
+            let id = game
+                .get("participantIdentities")?
+                .as_array()?
+                .iter()
+                .find(|i| i.get("player").and_then(|p| p.get("puuid")?.as_str()) == Some(puuid))?
+                .get("participantId")?
+                .as_i64()?;
+
+            participants
+                .iter()
+                .find(|p| p.get("participantId").and_then(Value::as_i64) == Some(id))?
+        }
+        None => participants.first()?,
+    };
+
+    let stats = participant.get("stats")?;
+    let count = |key: &str| stats.get(key).and_then(Value::as_u64).unwrap_or(0) as u32;
+
+    Some(MatchRecord {
+        game_id: game.get("gameId")?.as_i64()?,
+        queue_id: game.get("queueId").and_then(Value::as_i64).unwrap_or(0),
+        played_at: DateTime::<Utc>::from_timestamp_millis(game.get("gameCreation")?.as_i64()?)?,
+        champion_id: participant.get("championId")?.as_i64()? as i32,
+        role: participant.get("timeline").and_then(role_of),
+        win: stats.get("win").and_then(Value::as_bool).unwrap_or(false),
+        kills: count("kills"),
+        deaths: count("deaths"),
+        assists: count("assists"),
+    })
+}
+
+#[async_trait]
+impl MatchHistoryPort for LcuMatchHistory {
+    async fn recent_matches(&self, count: usize) -> Result<Vec<MatchRecord>> {
+        let res = self
+            .api
+            .get(&format!(
+                "/lol-match-history/v1/products/lol/current-summoner/matches?begIndex=0&endIndex={}",
+                count
+            ))
+            .await?;
+
+        let games = res
+            .get("games")
+            .and_then(|g| g.get("games"))
+            .and_then(|g| g.as_array())
+            .ok_or_else(|| anyhow!("match history has no games"))?;
+
+        Ok(games.iter().filter_map(|g| parse_game(g, None)).collect())
+    }
+
+    async fn game(&self, game_id: i64) -> Result<Option<MatchRecord>> {
+        let puuid = self.puuid().await?;
+        let game = self
+            .api
+            .get(&format!("/lol-match-history/v1/games/{}", game_id))
+            .await?;
+
+        Ok(parse_game(&game, Some(&puuid)))
+    }
+}
@@ -0,0 +1,111 @@
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use serde_json::Value;
+use std::sync::Arc;
+
+use crate::domain::ports::{LcuApiPort, SkinCatalogPort};
+use crate::domain::skin::{Chroma, Skin};
+
+pub struct LcuSkinCatalog {
+    api: Arc<dyn LcuApiPort>,
+}
+
+impl LcuSkinCatalog {
+    pub fn new(api: Arc<dyn LcuApiPort>) -> Self {
+        Self { api }
+    }
+
+    async fn summoner_id(&self) -> Result<i64> {
+        let me = self.api.get("/lol-summoner/v1/current-summoner").await?;
+        me.get("summonerId")
+            .and_then(|v| v.as_i64())
+            .ok_or_else(|| anyhow!("current-summoner has no summonerId"))
+    }
+
+    fn owned(entry: &Value) -> bool {
+        entry
+            .get("ownership")
+            .and_then(|o| o.get("owned"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+    }
+
+    fn unlocked(entry: &Value) -> bool {
+        let unlocked = entry
+            .get("unlocked")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let disabled = entry
+            .get("disabled")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        unlocked && !disabled
+    }
+
+    fn parse_skin(entry: &Value, chromas_key: &str, available: fn(&Value) -> bool) -> Option<Skin> {
+        let chromas = entry
+            .get(chromas_key)
+            .and_then(|v| v.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter(|c| available(c))
+                    .filter_map(|c| {
+                        Some(Chroma {
+                            id: c.get("id")?.as_i64()? as i32,
+                            name: c.get("name").and_then(|v| v.as_str()).unwrap_or("").into(),
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(Skin {
+            id: entry.get("id")?.as_i64()? as i32,
+            champion_id: entry.get("championId")?.as_i64()? as i32,
+            name: entry.get("name").and_then(|v| v.as_str()).unwrap_or("").into(),
+            is_base: entry.get("isBase").and_then(|v| v.as_bool()).unwrap_or(false),
+            chromas,
+        })
+    }
+}
+
+#[async_trait]
+impl SkinCatalogPort for LcuSkinCatalog {
+    async fn owned_skins(&self, champion_id: i32) -> Result<Vec<Skin>> {
+        let summoner = self.summoner_id().await?;
+        let path = format!(
+            "/lol-champions/v1/inventories/{}/champions/{}/skins",
+            summoner, champion_id
+        );
+
+        let skins = self.api.get(&path).await?;
+
+        Ok(skins
+            .as_array()
+            .map(|arr| {
+                arr.iter()
+                    .filter(|s| Self::owned(s))
+                    .filter_map(|s| Self::parse_skin(s, "chromas", Self::owned))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    async fn carousel_skins(&self) -> Result<Vec<Skin>> {
+        let skins = self
+            .api
+            .get("/lol-champ-select/v1/skin-carousel-skins")
+            .await?;
+
+        Ok(skins
+            .as_array()
+            .map(|arr| {
+                arr.iter()
+                    .filter(|s| Self::unlocked(s))
+                    .filter_map(|s| Self::parse_skin(s, "childSkins", Self::unlocked))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+}
@@ -0,0 +1,120 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use serde_json::json;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use crate::domain::item_set::ItemSet;
+use crate::domain::ports::{ItemSetWriterPort, LockfilePort};
+
+/// File prefix marking sets written by Overseer, so stale ones can be removed.
+const FILE_PREFIX: &str = "Overseer_";
+
+/// Writes item sets into `<League>/Config/Champions/<Key>/Recommended`.
+/// The install directory defaults to the one holding the lockfile.
+pub struct LeagueConfigItemSetWriter {
+    lockfile: Arc<dyn LockfilePort>,
+    install_dir: RwLock<Option<PathBuf>>,
+}
+
+impl LeagueConfigItemSetWriter {
+    pub fn new(lockfile: Arc<dyn LockfilePort>) -> Self {
+        Self {
+            lockfile,
+            install_dir: RwLock::new(None),
+        }
+    }
+
+    pub fn set_install_dir(&self, dir: Option<PathBuf>) {
+        *self.install_dir.write().unwrap() = dir;
+    }
+
+    fn recommended_dir(&self, champion_key: &str) -> Result<PathBuf> {
+        let install_dir = match self.install_dir.read().unwrap().clone() {
+            Some(dir) => dir,
+            None => self.lockfile.install_dir()?,
+        };
+
+        Ok(install_dir
+            .join("Config")
+            .join("Champions")
+            .join(champion_key)
+            .join("Recommended"))
+    }
+
+    fn remove_previous(dir: &Path) -> Result<()> {
+        if !dir.exists() {
+            return Ok(());
+        }
+
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            let ours = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .map(|n| n.starts_with(FILE_PREFIX))
+                .unwrap_or(false);
+
+            if ours {
+                fs::remove_file(&path)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ItemSetWriterPort for LeagueConfigItemSetWriter {
+    async fn write(&self, champion_id: i32, champion_key: &str, sets: &[ItemSet]) -> Result<()> {
+        let dir = self.recommended_dir(champion_key)?;
+        Self::remove_previous(&dir)?;
+
+        if sets.is_empty() {
+            return Ok(());
+        }
+
+        fs::create_dir_all(&dir)?;
+
+        for set in sets {
+            // The id becomes the file name; never let it leave the directory.
+            if !set.has_valid_id() {
+                log::warn!("ItemSets: skipping set with invalid id {:?}", set.id);
+                continue;
+            }
+
+            let blocks: Vec<_> = set
+                .blocks
+                .iter()
+                .map(|b| {
+                    json!({
+                        "type": b.title,
+                        "items": b.items.iter().map(|id| json!({
+                            "id": id.to_string(),
+                            "count": 1
+                        })).collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            let body = json!({
+                "title": set.title,
+                "type": "custom",
+                "map": "any",
+                "mode": "any",
+                "priority": false,
+                "sortrank": 0,
+                "associatedChampions": [champion_id],
+                "associatedMaps": set.map_ids,
+                "blocks": blocks
+            });
+
+            let file = dir.join(format!("{}{}.json", FILE_PREFIX, set.id));
+            fs::write(&file, serde_json::to_string_pretty(&body)?)?;
+            log::info!("ItemSets: wrote {}", file.display());
+        }
+
+        Ok(())
+    }
+}
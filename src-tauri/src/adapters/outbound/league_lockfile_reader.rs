@@ -1,5 +1,5 @@
 use crate::domain::ports::{LockfileData, LockfilePort};
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use std::{fs, path::PathBuf};
 
 pub struct LeagueLockfileReader {
@@ -25,4 +25,11 @@ impl LockfilePort for LeagueLockfileReader {
             password: parts[3].to_string(),
         })
     }
+
+    fn install_dir(&self) -> Result<PathBuf> {
+        self.path
+            .parent()
+            .map(|p| p.to_path_buf())
+            .ok_or_else(|| anyhow!("lockfile path has no parent"))
+    }
 }
@@ -1,9 +1,20 @@
 pub mod data_dragon_api;
 pub mod data_dragon_champion_resolver;
+pub mod data_dragon_item_catalog;
+pub mod data_dragon_rune_catalogue;
+pub mod dry_run_lcu_api;
 pub(crate) mod ingame_api_client;
+pub mod jsonl_champ_select_history;
 pub(crate) mod lcu_api;
+pub mod lcu_champion_availability;
 pub mod lcu_champion_banner;
 pub mod lcu_champion_hoverer;
 pub mod lcu_champion_picker;
+pub mod lcu_chat;
+pub mod lcu_item_set_writer;
+pub mod lcu_match_history;
+pub mod lcu_skin_catalog;
+pub mod league_config_item_set_writer;
 pub mod league_lockfile_reader;
 pub(crate) mod tauri_log_publisher;
+pub(crate) mod tauri_ui_event_publisher;
@@ -0,0 +1,25 @@
+use crate::domain::ports::UiEventPublisherPort;
+use serde_json::Value;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter};
+
+pub struct TauriUiEventPublisherAdapter {
+    app: AppHandle,
+}
+
+impl TauriUiEventPublisherAdapter {
+    pub fn new(app: AppHandle) -> Arc<Self> {
+        Arc::new(Self { app })
+    }
+}
+
+impl UiEventPublisherPort for TauriUiEventPublisherAdapter {
+    fn emit(&self, event: &str, payload: Value) {
+        let app = self.app.clone();
+        let event = event.to_string();
+
+        tauri::async_runtime::spawn(async move {
+            let _ = app.emit(&event, payload);
+        });
+    }
+}
@@ -1,14 +1,23 @@
+use crate::domain::champ_select::availability::ChampionAvailability;
 use crate::domain::champ_select::models::ChampSelectSessionPayload;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Clone)]
 pub struct BanPickRequest {
+    pub game_id: i64,
     pub role: String,
     pub banned: Vec<i32>,
     pub my_team: Vec<i32>,
     pub their_team: Vec<i32>,
+    /// Enemy champion id → normalized assigned position, when the client reveals it.
+    pub their_positions: HashMap<i32, String>,
     pub intended_picks: Vec<i32>,
     pub queue_id: i64,
     pub my_pick: Option<i32>,
+    /// Availability sets from the client; `None` means unknown and is not filtered on.
+    pub pickable: Option<HashSet<i32>>,
+    pub bannable: Option<HashSet<i32>>,
+    pub owned: Option<HashSet<i32>>,
 }
 
 impl BanPickRequest {
@@ -58,14 +67,97 @@ impl BanPickRequest {
             }
         }
 
+        let their_positions = session
+            .theirTeam
+            .iter()
+            .filter(|p| p.championId != 0)
+            .filter_map(|p| {
+                let pos = normalize_position(p.assignedPosition.as_deref()?)?;
+                Some((p.championId as i32, pos))
+            })
+            .collect();
+
         Self {
+            game_id: session.gameId,
             role: role.to_string(),
             banned,
             my_team: my_team_locked,
             their_team: their_team_locked,
+            their_positions,
             intended_picks: intended,
             queue_id: session.queueId,
             my_pick,
+            pickable: None,
+            bannable: None,
+            owned: None,
+        }
+    }
+
+    pub fn with_availability(mut self, availability: ChampionAvailability) -> Self {
+        self.pickable = availability.pickable;
+        self.bannable = availability.bannable;
+        self.owned = availability.owned;
+        self
+    }
+
+    /// Enemy champion assigned to the same position as us, if known.
+    pub fn lane_opponent(&self) -> Option<i32> {
+        self.their_positions
+            .iter()
+            .find(|(_, pos)| pos.eq_ignore_ascii_case(&self.role))
+            .map(|(cid, _)| *cid)
+    }
+
+    /// Why `cid` cannot be picked right now, if it cannot.
+    pub fn pick_blocker(&self, cid: i32) -> Option<&'static str> {
+        if self.banned.contains(&cid) {
+            return Some("banned");
+        }
+
+        if self.their_team.contains(&cid) {
+            return Some("enemy picked");
+        }
+
+        if self.my_team.contains(&cid) {
+            return Some("ally picked");
+        }
+
+        if self.owned.as_ref().is_some_and(|s| !s.contains(&cid)) {
+            return Some("not owned");
         }
+
+        if self.pickable.as_ref().is_some_and(|s| !s.contains(&cid)) {
+            return Some("not pickable");
+        }
+
+        None
+    }
+
+    /// Why `cid` cannot be banned right now, if it cannot.
+    pub fn ban_blocker(&self, cid: i32) -> Option<&'static str> {
+        if self.banned.contains(&cid) {
+            return Some("already banned");
+        }
+
+        if self.intended_picks.contains(&cid) {
+            return Some("ally using");
+        }
+
+        if self.bannable.as_ref().is_some_and(|s| !s.contains(&cid)) {
+            return Some("not bannable");
+        }
+
+        None
+    }
+}
+
+/// Maps LCU positions to the role names used in preferences.
+pub fn normalize_position(raw: &str) -> Option<String> {
+    let role = raw.to_lowercase();
+    match role.as_str() {
+        "" => None,
+        "utility" => Some("support".into()),
+        "middle" => Some("mid".into()),
+        _ => Some(role),
     }
 }
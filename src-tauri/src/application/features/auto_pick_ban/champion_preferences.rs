@@ -2,11 +2,14 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+use crate::domain::champ_select::queue::{self, ScopedRoleMap};
 use crate::domain::ports::ChampionResolverPort;
 use log::info;
 
+/// Champion lists keyed by (queue scope, role). A queue uses its own list,
+/// then its group's, then the `default` scope's.
 pub struct ChampionPreferences {
-    data: RwLock<HashMap<String, Vec<i32>>>,
+    data: RwLock<ScopedRoleMap<i32>>,
 }
 
 impl ChampionPreferences {
@@ -16,30 +19,34 @@ impl ChampionPreferences {
         }
     }
 
-    pub async fn get_for_role(&self, role: &str) -> Vec<i32> {
+    pub async fn get_for(&self, queue_id: i64, role: &str) -> Vec<i32> {
         let g = self.data.read().await;
-        g.get(&role.to_lowercase()).cloned().unwrap_or_default()
+        queue::lookup(&g, queue_id, role)
     }
 
     pub async fn resolve_from_names(
         &self,
-        role_map: HashMap<String, Vec<String>>,
+        scoped_names: ScopedRoleMap<String>,
         resolver: Arc<dyn ChampionResolverPort>,
     ) {
-        let mut resolved: HashMap<String, Vec<i32>> = HashMap::new();
+        let mut resolved: ScopedRoleMap<i32> = HashMap::new();
 
-        for (role, names) in role_map {
-            let mut list = Vec::new();
+        for (scope, role_map) in scoped_names {
+            let roles = resolved.entry(scope.to_lowercase()).or_default();
 
-            for name in names {
-                if let Some(id) = resolver.resolve_id(&name).await {
-                    list.push(id);
-                } else {
-                    info!("⚠ Unknown champion in preferences: {}", name);
+            for (role, names) in role_map {
+                let mut ids = Vec::new();
+
+                for name in names {
+                    if let Some(id) = resolver.resolve_id(&name).await {
+                        ids.push(id);
+                    } else {
+                        info!("⚠ Unknown champion in preferences: {}", name);
+                    }
                 }
-            }
 
-            resolved.insert(role.to_lowercase(), list);
+                roles.insert(role.to_lowercase(), ids);
+            }
         }
 
         let mut g = self.data.write().await;
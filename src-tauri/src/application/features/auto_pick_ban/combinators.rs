@@ -0,0 +1,287 @@
+use crate::application::features::auto_pick_ban::ban_pick_request::BanPickRequest;
+use crate::domain::champ_select::candidate::Candidate;
+use crate::domain::champ_select::decision::{Decision, DecisionTrace};
+use crate::domain::champ_select::matchup_table::MatchupTable;
+use crate::domain::match_stats::MatchStats;
+use crate::domain::ports::{BanStrategy, CandidateSource, ChampionResolverPort, PickingStrategy};
+use async_trait::async_trait;
+use log::info;
+use rand::distributions::{Distribution, WeightedIndex};
+use std::sync::Arc;
+
+/// First source that yields any candidate wins.
+pub struct FallbackSource {
+    steps: Vec<Arc<dyn CandidateSource>>,
+}
+
+impl FallbackSource {
+    pub fn new(steps: Vec<Arc<dyn CandidateSource>>) -> Self {
+        Self { steps }
+    }
+}
+
+#[async_trait]
+impl CandidateSource for FallbackSource {
+    async fn candidates(&self, req: &BanPickRequest, trace: &mut DecisionTrace) -> Vec<Candidate> {
+        for (i, step) in self.steps.iter().enumerate() {
+            let out = step.candidates(req, trace).await;
+            if !out.is_empty() {
+                info!(
+                    "[Pipeline] Fallback step {} produced {} candidate(s)",
+                    i,
+                    out.len()
+                );
+                if i > 0 {
+                    trace.note(format!("fallback step {} of {}", i + 1, self.steps.len()));
+                }
+                return out;
+            }
+        }
+
+        trace.note(format!("all {} fallback steps empty", self.steps.len()));
+        Vec::new()
+    }
+}
+
+pub enum ChampionFilter {
+    /// Drops champions the summoner does not own (or rent).
+    ExcludeUnowned,
+    /// Drops champions carrying any of the given Data Dragon tags.
+    ExcludeTags {
+        tags: Vec<String>,
+        resolver: Arc<dyn ChampionResolverPort>,
+    },
+}
+
+pub struct FilterSource {
+    inner: Arc<dyn CandidateSource>,
+    filter: ChampionFilter,
+}
+
+impl FilterSource {
+    pub fn new(inner: Arc<dyn CandidateSource>, filter: ChampionFilter) -> Self {
+        Self { inner, filter }
+    }
+}
+
+#[async_trait]
+impl CandidateSource for FilterSource {
+    async fn candidates(&self, req: &BanPickRequest, trace: &mut DecisionTrace) -> Vec<Candidate> {
+        let candidates = self.inner.candidates(req, trace).await;
+        let before = candidates.len();
+
+        let out: Vec<Candidate> = match &self.filter {
+            ChampionFilter::ExcludeUnowned => {
+                let Some(owned) = &req.owned else {
+                    info!("[Pipeline] Owned champions unavailable, not filtering");
+                    return candidates;
+                };
+
+                candidates
+                    .into_iter()
+                    .filter(|c| {
+                        let keep = owned.contains(&c.champion_id);
+                        if !keep {
+                            trace.reject(c.champion_id, "unowned");
+                        }
+                        keep
+                    })
+                    .collect()
+            }
+
+            ChampionFilter::ExcludeTags { tags, resolver } => {
+                let mut out = Vec::new();
+                for c in candidates {
+                    let champ_tags = resolver.resolve_tags(c.champion_id).await;
+                    let excluded = champ_tags
+                        .iter()
+                        .find(|t| tags.iter().any(|x| x.eq_ignore_ascii_case(t)));
+
+                    match excluded {
+                        Some(tag) => trace.reject(c.champion_id, format!("tagged {tag}")),
+                        None => out.push(c),
+                    }
+                }
+                out
+            }
+        };
+
+        if out.len() < before {
+            let what = match &self.filter {
+                ChampionFilter::ExcludeUnowned => "unowned".to_string(),
+                ChampionFilter::ExcludeTags { tags, .. } => tags.join("/"),
+            };
+            trace.note(format!("filtered out {} {what}", before - out.len()));
+        }
+
+        out
+    }
+}
+
+pub enum Scorer {
+    /// Matchup table score into the lane opponent; adds nothing when unknown.
+    Matchup(Arc<MatchupTable>),
+    /// Own win rate on the champion in this role and queue, centered on 50%;
+    /// adds nothing without games.
+    Performance(Arc<MatchStats>),
+}
+
+/// Adds `weight * scorer` to each candidate's score and re-sorts.
+pub struct ScoreSource {
+    inner: Arc<dyn CandidateSource>,
+    scorer: Scorer,
+    weight: f64,
+}
+
+impl ScoreSource {
+    pub fn new(inner: Arc<dyn CandidateSource>, scorer: Scorer, weight: f64) -> Self {
+        Self {
+            inner,
+            scorer,
+            weight,
+        }
+    }
+}
+
+#[async_trait]
+impl CandidateSource for ScoreSource {
+    async fn candidates(&self, req: &BanPickRequest, trace: &mut DecisionTrace) -> Vec<Candidate> {
+        let mut candidates = self.inner.candidates(req, trace).await;
+
+        trace.note(match &self.scorer {
+            Scorer::Matchup(_) => format!("scored by matchup (weight {})", self.weight),
+            Scorer::Performance(_) => format!("scored by performance (weight {})", self.weight),
+        });
+
+        for c in candidates.iter_mut() {
+            let bonus = match &self.scorer {
+                Scorer::Matchup(table) => match req.lane_opponent() {
+                    Some(opp) => table.score(c.champion_id, opp).await.unwrap_or(0.0),
+                    None => 0.0,
+                },
+                Scorer::Performance(stats) => stats
+                    .stats_for(c.champion_id, Some(&req.role), Some(req.queue_id))
+                    .win_rate()
+                    .map(|wr| wr - 0.5)
+                    .unwrap_or(0.0),
+            };
+            c.score += self.weight * bonus;
+        }
+
+        candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+        candidates
+    }
+}
+
+/// Moves a weighted-random pick from the top `n` candidates to the front.
+/// Without explicit weights, rank `i` of `n` gets weight `n - i`.
+/// A current hover that is still in the top `n` is kept, so re-deciding on
+/// every session update does not flip between champions.
+pub struct RandomTopSource {
+    inner: Arc<dyn CandidateSource>,
+    n: usize,
+    weights: Option<Vec<f64>>,
+}
+
+impl RandomTopSource {
+    pub fn new(inner: Arc<dyn CandidateSource>, n: usize, weights: Option<Vec<f64>>) -> Self {
+        Self { inner, n, weights }
+    }
+
+    fn pick_index(&self, available: usize) -> Option<usize> {
+        let top = self.n.min(available);
+        if top == 0 {
+            return None;
+        }
+
+        let weights: Vec<f64> = match &self.weights {
+            Some(w) => (0..top).map(|i| w.get(i).copied().unwrap_or(0.0)).collect(),
+            None => (0..top).map(|i| (top - i) as f64).collect(),
+        };
+
+        let dist = WeightedIndex::new(&weights).ok()?;
+        Some(dist.sample(&mut rand::thread_rng()))
+    }
+}
+
+#[async_trait]
+impl CandidateSource for RandomTopSource {
+    async fn candidates(&self, req: &BanPickRequest, trace: &mut DecisionTrace) -> Vec<Candidate> {
+        let mut candidates = self.inner.candidates(req, trace).await;
+
+        let current = req.my_pick.and_then(|cid| {
+            candidates
+                .iter()
+                .take(self.n)
+                .position(|c| c.champion_id == cid)
+        });
+
+        if let Some(i) = current {
+            let kept = candidates.remove(i);
+            trace.note(format!(
+                "kept current hover (rank {} of top {})",
+                i + 1,
+                self.n
+            ));
+            candidates.insert(0, kept);
+        } else if let Some(i) = self.pick_index(candidates.len()) {
+            let chosen = candidates.remove(i);
+            info!(
+                "[Pipeline] Randomly chose champion_id={} (rank {} of top {})",
+                chosen.champion_id,
+                i + 1,
+                self.n
+            );
+            trace.note(format!("random pick (rank {} of top {})", i + 1, self.n));
+            candidates.insert(0, chosen);
+        }
+
+        candidates
+    }
+}
+
+/// Turns a candidate pipeline into a pick or ban strategy: the first candidate wins.
+pub struct PipelineStrategy {
+    root: Arc<dyn CandidateSource>,
+}
+
+impl PipelineStrategy {
+    pub fn new(root: Arc<dyn CandidateSource>) -> Self {
+        Self { root }
+    }
+
+    async fn decide_first(&self, req: &BanPickRequest) -> Decision {
+        // Sources reject what they drop and note the steps they take on the trace.
+        let mut trace = DecisionTrace::new("pipeline");
+        let candidates = self.root.candidates(req, &mut trace).await;
+
+        for c in &candidates {
+            trace.accept(c.champion_id, Some(c.score));
+        }
+
+        match candidates.first() {
+            Some(c) => {
+                info!("[Pipeline] ✔ Decided champion_id={}", c.champion_id);
+                trace.choose(c.champion_id, "first pipeline candidate")
+            }
+            None => {
+                info!("[Pipeline] No candidate for role={}", req.role);
+                trace.nothing("pipeline produced no candidates")
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl PickingStrategy for PipelineStrategy {
+    async fn decide(&self, request: &BanPickRequest) -> Decision {
+        self.decide_first(request).await
+    }
+}
+
+#[async_trait]
+impl BanStrategy for PipelineStrategy {
+    async fn decide(&self, request: &BanPickRequest) -> Decision {
+        self.decide_first(request).await
+    }
+}
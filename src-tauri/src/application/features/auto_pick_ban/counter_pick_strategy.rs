@@ -0,0 +1,125 @@
+use crate::application::features::auto_pick_ban::ban_pick_request::BanPickRequest;
+use crate::application::features::auto_pick_ban::champion_preferences::ChampionPreferences;
+use crate::application::features::auto_pick_ban::priority_pick_strategy::PriorityPickStrategy;
+use crate::domain::champ_select::candidate::Candidate;
+use crate::domain::champ_select::decision::{Decision, DecisionTrace};
+use crate::domain::champ_select::matchup_table::MatchupTable;
+use crate::domain::ports::{CandidateSource, PickingStrategy};
+use log::info;
+use std::sync::Arc;
+
+/// Picks the pool champion with the best matchup score into the lane opponent.
+/// Falls back to priority order when the opponent or every score is unknown.
+pub struct CounterPickStrategy {
+    prefs: Arc<ChampionPreferences>,
+    matchups: Arc<MatchupTable>,
+    fallback: Arc<PriorityPickStrategy>,
+}
+
+impl CounterPickStrategy {
+    pub fn new(
+        prefs: Arc<ChampionPreferences>,
+        matchups: Arc<MatchupTable>,
+        fallback: Arc<PriorityPickStrategy>,
+    ) -> Self {
+        Self {
+            prefs,
+            matchups,
+            fallback,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl PickingStrategy for CounterPickStrategy {
+    async fn decide(&self, request: &BanPickRequest) -> Decision {
+        let Some(opponent) = request.lane_opponent() else {
+            info!(
+                "[CounterPickStrategy] Lane opponent unknown for role={}, using priority",
+                request.role
+            );
+            return self
+                .fallback
+                .decide(request)
+                .await
+                .via("counter", "lane opponent unknown");
+        };
+
+        let role_prefs = self.prefs.get_for(request.queue_id, &request.role).await;
+        info!(
+            "[CounterPickStrategy] Role={}, Opponent={}, Prefs={:?}",
+            request.role, opponent, role_prefs
+        );
+
+        let mut trace = DecisionTrace::new("counter");
+        let mut best: Option<(i32, f64)> = None;
+
+        for cid in role_prefs {
+            if let Some(reason) = request.pick_blocker(cid) {
+                info!("[CounterPickStrategy] Skipping {cid} ({reason})");
+                trace.reject(cid, reason);
+                continue;
+            }
+
+            let Some(score) = self.matchups.score(cid, opponent).await else {
+                info!("[CounterPickStrategy] No matchup for {cid} vs {opponent}");
+                trace.reject(cid, "no matchup data");
+                continue;
+            };
+
+            info!("[CounterPickStrategy] {cid} vs {opponent} scores {score}");
+            trace.accept(cid, Some(score));
+
+            // Strictly greater keeps the earlier (higher priority) champion on ties.
+            if best.map(|(_, s)| score > s).unwrap_or(true) {
+                best = Some((cid, score));
+            }
+        }
+
+        match best {
+            Some((cid, score)) => {
+                info!("[CounterPickStrategy] ✔ Decided to pick champion_id={cid} ({score})");
+                trace.choose(cid, format!("best matchup into {opponent}"))
+            }
+            None => {
+                info!("[CounterPickStrategy] No scored matchup, using priority");
+                self.fallback
+                    .decide(request)
+                    .await
+                    .via("counter", "no scored matchup")
+            }
+        }
+    }
+}
+
+/// Only champions with a known matchup into the lane opponent, best score first.
+#[async_trait::async_trait]
+impl CandidateSource for CounterPickStrategy {
+    async fn candidates(
+        &self,
+        request: &BanPickRequest,
+        trace: &mut DecisionTrace,
+    ) -> Vec<Candidate> {
+        let Some(opponent) = request.lane_opponent() else {
+            trace.note("counter: lane opponent unknown");
+            return Vec::new();
+        };
+
+        let mut out = Vec::new();
+        for cid in self.prefs.get_for(request.queue_id, &request.role).await {
+            if let Some(reason) = request.pick_blocker(cid) {
+                trace.reject(cid, reason);
+                continue;
+            }
+
+            match self.matchups.score(cid, opponent).await {
+                Some(score) => out.push(Candidate::new(cid, score)),
+                None => trace.reject(cid, "no matchup into lane opponent"),
+            }
+        }
+
+        // Stable sort keeps priority order among equal scores.
+        out.sort_by(|a, b| b.score.total_cmp(&a.score));
+        out
+    }
+}
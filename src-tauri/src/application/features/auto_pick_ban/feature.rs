@@ -1,8 +1,22 @@
 use crate::application::features::auto_pick_ban::champion_preferences::ChampionPreferences;
+use crate::application::features::auto_pick_ban::performance_pick_strategy::PerformancePickStrategy;
+use crate::application::features::auto_pick_ban::role_strategy_selector::{
+    BanStrategySelector, PickStrategyKind, RoleStrategy, RoleStrategySelector,
+};
+use crate::application::features::auto_pick_ban::scripted_strategy::{
+    ScriptSide, ScriptedStrategy,
+};
+use crate::application::features::auto_pick_ban::strategy_pipeline::{
+    PipelineBuilder, PipelineSide,
+};
+use crate::domain::champ_select::queue::{ScopedRoleMap, DEFAULT_SCOPE};
+use crate::domain::champ_select::session_control::SessionControl;
+use crate::domain::delay_state::DelayState;
+use crate::domain::role_resolver::RoleResolver;
 use crate::domain::feature::{Feature, FeatureId};
 use crate::domain::ports::{
     BannerPort, ChampionResolverPort, HovererPort, LeagueEventPublisherPort, LeagueEventSubscriber,
-    PickerPort,
+    LogPublisherPort, PickerPort, UiEventPublisherPort,
 };
 use serde_json::Value;
 use std::collections::HashMap;
@@ -18,9 +32,52 @@ pub struct AutoPickBanFeature {
     pick_prefs: Arc<ChampionPreferences>,
     ban_prefs: Arc<BanPreferences>,
     resolver: Arc<dyn ChampionResolverPort>,
+    selector: Arc<RoleStrategySelector>,
+    ban_selector: Arc<BanStrategySelector>,
+    scripted: Arc<ScriptedStrategy>,
+    performance: Arc<PerformancePickStrategy>,
+    pipelines: Arc<PipelineBuilder>,
+    lock_ms_left: Arc<DelayState>,
+}
+
+fn names(val: &Value) -> Vec<String> {
+    val.as_array()
+        .unwrap_or(&vec![])
+        .iter()
+        .filter_map(|v| v.as_str().map(|x| x.to_string()))
+        .collect()
+}
+
+/// Accepts `{ role: [names] }` for lists that apply to every queue, and
+/// `{ scope: { role: [names] } }` where scope is a queue id, a queue group
+/// (`ranked`, `draft`, `blind`, `swiftplay`, `quickplay`, `clash`) or
+/// `default`.
+fn parse_scoped_names(val: &Value) -> Option<ScopedRoleMap<String>> {
+    let obj = val.as_object()?;
+    let mut scoped = ScopedRoleMap::<String>::new();
+
+    for (key, entry) in obj {
+        match entry {
+            Value::Object(roles) => {
+                let scope = scoped.entry(key.to_lowercase()).or_default();
+                for (role, arr) in roles {
+                    scope.insert(role.clone(), names(arr));
+                }
+            }
+            _ => {
+                scoped
+                    .entry(DEFAULT_SCOPE.to_string())
+                    .or_default()
+                    .insert(key.clone(), names(entry));
+            }
+        }
+    }
+
+    Some(scoped)
 }
 
 impl AutoPickBanFeature {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         league_pub: Arc<dyn LeagueEventPublisherPort>,
         hoverer: Arc<dyn HovererPort>,
@@ -29,9 +86,27 @@ impl AutoPickBanFeature {
         pick_prefs: Arc<ChampionPreferences>,
         ban_prefs: Arc<BanPreferences>,
         resolver: Arc<dyn ChampionResolverPort>,
+        selector: Arc<RoleStrategySelector>,
+        ban_selector: Arc<BanStrategySelector>,
+        scripted: Arc<ScriptedStrategy>,
+        performance: Arc<PerformancePickStrategy>,
+        pipelines: Arc<PipelineBuilder>,
+        control: Arc<SessionControl>,
+        log_pub: Arc<dyn LogPublisherPort>,
+        ui_pub: Arc<dyn UiEventPublisherPort>,
+        roles: Arc<RoleResolver>,
     ) -> Self {
-        let subscriber =
-            AutoPickBanSubscriber::new(hoverer.clone(), picker.clone(), banner.clone());
+        let lock_ms_left = Arc::new(DelayState::new());
+        let subscriber = AutoPickBanSubscriber::new(
+            hoverer.clone(),
+            picker.clone(),
+            banner.clone(),
+            lock_ms_left.clone(),
+            control,
+            log_pub,
+            ui_pub,
+            roles,
+        );
 
         Self {
             league_pub,
@@ -39,60 +114,111 @@ impl AutoPickBanFeature {
             pick_prefs,
             ban_prefs,
             resolver,
+            selector,
+            ban_selector,
+            scripted,
+            performance,
+            pipelines,
+            lock_ms_left,
         }
     }
 
     fn apply_pick_settings(&self, val: &Value) {
-        let Some(obj) = val.as_object() else {
+        let Some(scoped_names) = parse_scoped_names(val) else {
             return;
         };
 
-        let mut role_to_names = HashMap::<String, Vec<String>>::new();
+        let prefs = self.pick_prefs.clone();
+        let resolver = self.resolver.clone();
 
-        for (role, arr) in obj {
-            let names = arr
-                .as_array()
-                .unwrap_or(&vec![])
-                .iter()
-                .filter_map(|v| v.as_str().map(|x| x.to_string()))
-                .collect::<Vec<_>>();
+        tauri::async_runtime::spawn(async move {
+            prefs.resolve_from_names(scoped_names, resolver).await;
+        });
+    }
 
-            role_to_names.insert(role.clone(), names);
-        }
+    fn apply_ban_settings(&self, val: &Value) {
+        let Some(scoped_names) = parse_scoped_names(val) else {
+            return;
+        };
 
-        let prefs = self.pick_prefs.clone();
+        let prefs = self.ban_prefs.clone();
         let resolver = self.resolver.clone();
 
         tauri::async_runtime::spawn(async move {
-            prefs.resolve_from_names(role_to_names, resolver).await;
+            prefs.resolve_from_names(scoped_names, resolver).await;
         });
     }
 
-    fn apply_ban_settings(&self, val: &Value) {
+    fn apply_strategy_settings(&self, val: &Value) {
         let Some(obj) = val.as_object() else {
             return;
         };
 
-        let mut role_to_names = HashMap::<String, Vec<String>>::new();
+        let mut strategies = HashMap::<String, RoleStrategy>::new();
 
-        for (role, arr) in obj {
-            let names = arr
-                .as_array()
-                .unwrap_or(&vec![])
-                .iter()
-                .filter_map(|v| v.as_str().map(|x| x.to_string()))
-                .collect::<Vec<_>>();
+        for (role, entry) in obj {
+            let strategy = match entry {
+                Value::Object(_) => self
+                    .pipelines
+                    .parse(PipelineSide::Pick, entry)
+                    .map(RoleStrategy::Pipeline),
+                _ => entry
+                    .as_str()
+                    .and_then(PickStrategyKind::parse)
+                    .map(RoleStrategy::Kind)
+                    .ok_or_else(|| format!("unknown pick strategy {entry}")),
+            };
 
-            role_to_names.insert(role.clone(), names);
+            match strategy {
+                Ok(s) => {
+                    strategies.insert(role.clone(), s);
+                }
+                Err(e) => log::info!("⚠ Pick strategy for {}: {}", role, e),
+            }
         }
 
-        let prefs = self.ban_prefs.clone();
-        let resolver = self.resolver.clone();
+        let selector = self.selector.clone();
 
         tauri::async_runtime::spawn(async move {
-            prefs.resolve_from_names(role_to_names, resolver).await;
+            selector.set_strategies(strategies).await;
         });
     }
+
+    fn apply_ban_strategy_settings(&self, val: &Value) {
+        let Some(obj) = val.as_object() else {
+            return;
+        };
+
+        let mut pipelines = HashMap::new();
+
+        for (role, entry) in obj {
+            match self.pipelines.parse(PipelineSide::Ban, entry) {
+                Ok(p) => {
+                    pipelines.insert(role.clone(), p);
+                }
+                Err(e) => log::info!("⚠ Ban strategy for {}: {}", role, e),
+            }
+        }
+
+        let selector = self.ban_selector.clone();
+
+        tauri::async_runtime::spawn(async move {
+            selector.set_pipelines(pipelines).await;
+        });
+    }
+
+    fn apply_script_settings(&self, side: ScriptSide, val: &Value) {
+        let Some(obj) = val.as_object() else {
+            return;
+        };
+
+        let sources = obj
+            .iter()
+            .filter_map(|(role, src)| Some((role.clone(), src.as_str()?.to_string())))
+            .collect::<HashMap<_, _>>();
+
+        self.scripted.compile_scripts(side, sources);
+    }
 }
 
 impl Feature for AutoPickBanFeature {
@@ -120,5 +246,31 @@ impl Feature for AutoPickBanFeature {
         if let Some(b) = settings.get("banPreferences") {
             self.apply_ban_settings(b);
         }
+
+        if let Some(s) = settings.get("pickStrategies") {
+            self.apply_strategy_settings(s);
+        }
+
+        if let Some(s) = settings.get("banStrategies") {
+            self.apply_ban_strategy_settings(s);
+        }
+
+        // Hover immediately and lock with this many seconds left; 0 or absent locks at once.
+        if let Some(v) = settings.get("lockSecondsLeft").and_then(|v| v.as_f64()) {
+            self.lock_ms_left.set((v.max(0.0) * 1000.0) as u64);
+        }
+
+        // How many places own results may move a champion in the performance strategy.
+        if let Some(v) = settings.get("performanceMaxShift").and_then(|v| v.as_u64()) {
+            self.performance.set_max_shift(v as usize);
+        }
+
+        if let Some(s) = settings.get("pickScripts") {
+            self.apply_script_settings(ScriptSide::Pick, s);
+        }
+
+        if let Some(s) = settings.get("banScripts") {
+            self.apply_script_settings(ScriptSide::Ban, s);
+        }
     }
 }
@@ -0,0 +1,43 @@
+use log::info;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::task::{self, JoinHandle};
+use tokio::time::sleep;
+
+/// Holds at most one pending lock-in. Scheduling a new one or cancelling
+/// aborts whatever was pending, so only the latest session is ever locked.
+pub struct LockScheduler {
+    pending: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl LockScheduler {
+    pub fn new() -> Self {
+        Self {
+            pending: Mutex::new(None),
+        }
+    }
+
+    pub fn schedule<F>(&self, delay: Duration, lock: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let handle = task::spawn(async move {
+            sleep(delay).await;
+            lock.await;
+        });
+
+        if let Some(previous) = self.pending.lock().unwrap().replace(handle) {
+            previous.abort();
+        }
+    }
+
+    pub fn cancel(&self) {
+        if let Some(previous) = self.pending.lock().unwrap().take() {
+            if !previous.is_finished() {
+                info!("[APB] Cancelled pending lock");
+            }
+            previous.abort();
+        }
+    }
+}
@@ -1,6 +1,14 @@
 pub mod ban_pick_request;
 pub mod champion_preferences;
+pub mod combinators;
+pub mod counter_pick_strategy;
 pub mod feature;
+pub mod lock_scheduler;
+pub mod performance_pick_strategy;
 pub mod priority_ban_strategy;
 pub mod priority_pick_strategy;
+pub mod reporting_strategy;
+pub mod role_strategy_selector;
+pub mod scripted_strategy;
+pub mod strategy_pipeline;
 pub mod subscriber;
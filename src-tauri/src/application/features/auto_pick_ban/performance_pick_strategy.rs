@@ -0,0 +1,262 @@
+use crate::application::features::auto_pick_ban::ban_pick_request::BanPickRequest;
+use crate::application::features::auto_pick_ban::champion_preferences::ChampionPreferences;
+use crate::domain::champ_select::candidate::{ranked, Candidate};
+use crate::domain::champ_select::decision::{Decision, DecisionTrace};
+use crate::domain::match_history::MatchRecord;
+use crate::domain::match_stats::MatchStats;
+use crate::domain::ports::{CandidateSource, PickingStrategy};
+use chrono::{DateTime, Utc};
+use log::info;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Pseudo-games at 50% every champion starts with, so a couple of lucky
+/// games do not vault a champion over the manual order.
+const PRIOR_GAMES: f64 = 5.0;
+/// A game this many days old counts half as much as one played today.
+const HALF_LIFE_DAYS: f64 = 21.0;
+const DEFAULT_MAX_SHIFT: usize = 2;
+
+/// Priority order, nudged by own recent results in the role. Each champion
+/// moves toward its rank by smoothed win rate, but never more than
+/// `max_shift` places from where the preference list puts it.
+pub struct PerformancePickStrategy {
+    prefs: Arc<ChampionPreferences>,
+    stats: Arc<MatchStats>,
+    max_shift: AtomicUsize,
+}
+
+impl PerformancePickStrategy {
+    pub fn new(prefs: Arc<ChampionPreferences>, stats: Arc<MatchStats>) -> Self {
+        Self {
+            prefs,
+            stats,
+            max_shift: AtomicUsize::new(DEFAULT_MAX_SHIFT),
+        }
+    }
+
+    pub fn set_max_shift(&self, places: usize) {
+        self.max_shift.store(places, Ordering::Relaxed);
+    }
+
+    /// Pool champions in adjusted order, each with its smoothed win rate.
+    async fn adjusted(&self, request: &BanPickRequest) -> Vec<Candidate> {
+        let pool = self.prefs.get_for(request.queue_id, &request.role).await;
+        let matches = self.stats.matches();
+        let now = Utc::now();
+
+        let forms: Vec<f64> = pool
+            .iter()
+            .map(|cid| form(&matches, *cid, &request.role, now))
+            .collect();
+
+        ordered(&forms, self.max_shift.load(Ordering::Relaxed))
+            .into_iter()
+            .map(|i| Candidate::new(pool[i], forms[i]))
+            .collect()
+    }
+}
+
+/// Manual indices in adjusted order, given each champion's form. Filled one
+/// position at a time: a champion about to fall more than `max_shift` places
+/// takes the position, otherwise the best-form champion that would not rise
+/// more than `max_shift` places does (manual order breaking ties).
+fn ordered(forms: &[f64], max_shift: usize) -> Vec<usize> {
+    let mut by_form: Vec<usize> = (0..forms.len()).collect();
+    by_form.sort_by(|a, b| forms[*b].total_cmp(&forms[*a]).then(a.cmp(b)));
+
+    let mut placed = vec![false; forms.len()];
+    let mut order = Vec::with_capacity(forms.len());
+
+    for p in 0..forms.len() {
+        let next = match p.checked_sub(max_shift) {
+            Some(due) if !placed[due] => due,
+            // Positions 0..p hold p champions, so one of the first p + max_shift + 1 is free.
+            _ => *by_form
+                .iter()
+                .find(|i| !placed[**i] && **i <= p + max_shift)
+                .unwrap(),
+        };
+        placed[next] = true;
+        order.push(next);
+    }
+
+    order
+}
+
+/// Recency-weighted win rate in the role, smoothed toward 50%.
+fn form(matches: &[MatchRecord], champion_id: i32, role: &str, now: DateTime<Utc>) -> f64 {
+    let (wins, games) = matches
+        .iter()
+        .filter(|m| {
+            m.champion_id == champion_id
+                && m.role
+                    .as_deref()
+                    .is_some_and(|r| r.eq_ignore_ascii_case(role))
+        })
+        .fold((0.0, 0.0), |(wins, games), m| {
+            let days = (now - m.played_at).num_hours().max(0) as f64 / 24.0;
+            let weight = 0.5f64.powf(days / HALF_LIFE_DAYS);
+            (wins + weight * m.win as u8 as f64, games + weight)
+        });
+
+    (wins + 0.5 * PRIOR_GAMES) / (games + PRIOR_GAMES)
+}
+
+#[async_trait::async_trait]
+impl PickingStrategy for PerformancePickStrategy {
+    async fn decide(&self, request: &BanPickRequest) -> Decision {
+        let mut trace = DecisionTrace::new("performance");
+
+        for c in self.adjusted(request).await {
+            if let Some(reason) = request.pick_blocker(c.champion_id) {
+                info!(
+                    "[PerformancePickStrategy] Skipping {} ({reason})",
+                    c.champion_id
+                );
+                trace.reject(c.champion_id, reason);
+                continue;
+            }
+
+            info!(
+                "[PerformancePickStrategy] ✔ Decided to pick champion_id={} (form {:.2})",
+                c.champion_id, c.score
+            );
+            trace.accept(c.champion_id, Some(c.score));
+            return trace.choose(c.champion_id, "highest available after results adjustment");
+        }
+
+        trace.nothing("no available champion in pool")
+    }
+}
+
+/// The adjusted order, blocked champions removed, scored by rank like priority.
+#[async_trait::async_trait]
+impl CandidateSource for PerformancePickStrategy {
+    async fn candidates(
+        &self,
+        request: &BanPickRequest,
+        trace: &mut DecisionTrace,
+    ) -> Vec<Candidate> {
+        let adjusted = self.adjusted(request).await;
+
+        ranked(adjusted.into_iter().map(|c| c.champion_id).filter(|cid| {
+            match request.pick_blocker(*cid) {
+                Some(reason) => {
+                    trace.reject(*cid, reason);
+                    false
+                }
+                None => true,
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn permutations(n: usize) -> Vec<Vec<usize>> {
+        if n == 0 {
+            return vec![Vec::new()];
+        }
+        permutations(n - 1)
+            .into_iter()
+            .flat_map(|p| {
+                (0..n).map(move |at| {
+                    let mut q = p.clone();
+                    q.insert(at, n - 1);
+                    q
+                })
+            })
+            .collect()
+    }
+
+    /// Forms that rank the champion at manual index `i` at `ranks[i]`.
+    fn forms_for(ranks: &[usize]) -> Vec<f64> {
+        ranks.iter().map(|r| 1.0 - *r as f64 / 10.0).collect()
+    }
+
+    fn game(champion_id: i32, role: &str, days_ago: i64, win: bool) -> MatchRecord {
+        MatchRecord {
+            game_id: 0,
+            queue_id: 420,
+            played_at: Utc::now() - Duration::days(days_ago),
+            champion_id,
+            role: Some(role.into()),
+            win,
+            kills: 0,
+            deaths: 0,
+            assists: 0,
+        }
+    }
+
+    #[test]
+    fn ordered_never_moves_a_champion_more_than_max_shift() {
+        for n in 1..=6 {
+            for ranks in permutations(n) {
+                for max_shift in 0..n {
+                    let order = ordered(&forms_for(&ranks), max_shift);
+
+                    let mut seen = order.clone();
+                    seen.sort();
+                    assert_eq!(seen, (0..n).collect::<Vec<_>>());
+
+                    for (p, i) in order.iter().enumerate() {
+                        assert!(
+                            p.abs_diff(*i) <= max_shift,
+                            "{ranks:?} shift {max_shift}: {order:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ordered_keeps_a_champion_from_falling_behind_its_window() {
+        let order = ordered(&forms_for(&[3, 4, 0, 1, 2]), 2);
+        assert_eq!(order, vec![2, 3, 0, 1, 4]);
+    }
+
+    #[test]
+    fn ordered_without_shift_is_the_manual_order() {
+        assert_eq!(ordered(&forms_for(&[2, 1, 0]), 0), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn ordered_with_a_wide_shift_is_the_form_order() {
+        assert_eq!(ordered(&forms_for(&[2, 0, 1]), 3), vec![1, 2, 0]);
+    }
+
+    #[test]
+    fn ordered_breaks_form_ties_by_manual_order() {
+        assert_eq!(ordered(&[0.5, 0.6, 0.6], 2), vec![1, 2, 0]);
+    }
+
+    #[test]
+    fn form_without_games_is_even() {
+        assert_eq!(form(&[], 1, "mid", Utc::now()), 0.5);
+    }
+
+    #[test]
+    fn form_smooths_a_single_win_toward_even() {
+        let f = form(&[game(1, "mid", 0, true)], 1, "mid", Utc::now());
+        assert!((f - 3.5 / 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn form_ignores_other_champions_and_roles() {
+        let matches = [game(2, "mid", 0, true), game(1, "top", 0, true)];
+        assert_eq!(form(&matches, 1, "mid", Utc::now()), 0.5);
+    }
+
+    #[test]
+    fn form_weighs_old_games_less() {
+        let now = Utc::now();
+        let recent = form(&[game(1, "mid", 0, true)], 1, "mid", now);
+        let old = form(&[game(1, "mid", 42, true)], 1, "mid", now);
+        assert!(old < recent && old > 0.5);
+    }
+}
@@ -1,7 +1,8 @@
 use crate::application::features::auto_pick_ban::ban_pick_request::BanPickRequest;
 use crate::domain::champ_select::ban_preferences::BanPreferences;
-use crate::domain::champ_select::decision::{choose, no_decision, Decision};
-use crate::domain::ports::BanStrategy;
+use crate::domain::champ_select::candidate::{ranked, Candidate};
+use crate::domain::champ_select::decision::{Decision, DecisionTrace};
+use crate::domain::ports::{BanStrategy, CandidateSource};
 use async_trait::async_trait;
 use log::{info, warn};
 use std::sync::Arc;
@@ -21,7 +22,7 @@ impl BanStrategy for PriorityBanStrategy {
     async fn decide(&self, request: &BanPickRequest) -> Decision {
         info!("[PriorityBanStrategy] Deciding ban from {:?}", request);
 
-        let role_prefs = self.prefs.get_for_role(&request.role).await;
+        let role_prefs = self.prefs.get_for(request.queue_id, &request.role).await;
 
         info!(
             "[PriorityBanStrategy] Role={}, Prefs={:?}",
@@ -32,21 +33,20 @@ impl BanStrategy for PriorityBanStrategy {
             request.banned, request.my_team
         );
 
+        let mut trace = DecisionTrace::new("priority");
+
         for cid in role_prefs {
             info!("[PriorityBanStrategy] Checking champion_id={cid}");
 
-            if request.banned.contains(&cid) {
-                info!("[PriorityBanStrategy] Skipping {cid} (already banned)");
-                continue;
-            }
-
-            if request.intended_picks.contains(&cid) {
-                info!("[PriorityBanStrategy] Skipping {cid} (ally using)");
+            if let Some(reason) = request.ban_blocker(cid) {
+                info!("[PriorityBanStrategy] Skipping {cid} ({reason})");
+                trace.reject(cid, reason);
                 continue;
             }
 
             info!("[PriorityBanStrategy] ✔ Decided to ban champion_id={cid}");
-            return choose(cid);
+            trace.accept(cid, None);
+            return trace.choose(cid, "highest priority available");
         }
 
         warn!(
@@ -54,6 +54,29 @@ impl BanStrategy for PriorityBanStrategy {
             request.role
         );
 
-        no_decision()
+        trace.nothing("no available ban target in pool")
+    }
+}
+
+#[async_trait]
+impl CandidateSource for PriorityBanStrategy {
+    async fn candidates(
+        &self,
+        request: &BanPickRequest,
+        trace: &mut DecisionTrace,
+    ) -> Vec<Candidate> {
+        let role_prefs = self.prefs.get_for(request.queue_id, &request.role).await;
+
+        ranked(
+            role_prefs
+                .into_iter()
+                .filter(|cid| match request.ban_blocker(*cid) {
+                    Some(reason) => {
+                        trace.reject(*cid, reason);
+                        false
+                    }
+                    None => true,
+                }),
+        )
     }
 }
@@ -1,7 +1,8 @@
 use crate::application::features::auto_pick_ban::ban_pick_request::BanPickRequest;
 use crate::application::features::auto_pick_ban::champion_preferences::ChampionPreferences;
-use crate::domain::champ_select::decision::{choose, no_decision, Decision};
-use crate::domain::ports::PickingStrategy;
+use crate::domain::champ_select::candidate::{ranked, Candidate};
+use crate::domain::champ_select::decision::{Decision, DecisionTrace};
+use crate::domain::ports::{CandidateSource, PickingStrategy};
 use log::{info, warn};
 use std::sync::Arc;
 
@@ -20,7 +21,7 @@ impl PickingStrategy for PriorityPickStrategy {
     async fn decide(&self, request: &BanPickRequest) -> Decision {
         info!("[PriorityPickStrategy] Picking from request={:?}", request);
 
-        let role_prefs = self.prefs.get_for_role(&request.role).await;
+        let role_prefs = self.prefs.get_for(request.queue_id, &request.role).await;
         info!(
             "[PriorityPickStrategy] Role={}, Prefs={:?}",
             request.role, role_prefs
@@ -30,33 +31,28 @@ impl PickingStrategy for PriorityPickStrategy {
             request.banned, request.their_team, request.my_team
         );
 
+        let mut trace = DecisionTrace::new("priority");
+
         for cid in role_prefs {
             // Match Python: if your current pick equals the target, return it
             if let Some(my) = request.my_pick {
                 if my == cid {
-                    return choose(cid);
+                    trace.accept(cid, None);
+                    return trace.choose(cid, "current pick is in the pool");
                 }
             }
 
             info!("[PriorityPickStrategy] Checking champion_id={cid}");
 
-            if request.banned.contains(&cid) {
-                info!("[PriorityPickStrategy] Skipping {cid} (banned)");
-                continue;
-            }
-
-            if request.their_team.contains(&cid) {
-                info!("[PriorityPickStrategy] Skipping {cid} (enemy picked)");
-                continue;
-            }
-
-            if request.my_team.contains(&cid) {
-                info!("[PriorityPickStrategy] Skipping {cid} (ally picked)");
+            if let Some(reason) = request.pick_blocker(cid) {
+                info!("[PriorityPickStrategy] Skipping {cid} ({reason})");
+                trace.reject(cid, reason);
                 continue;
             }
 
             info!("[PriorityPickStrategy] ✔ Decided to pick champion_id={cid}");
-            return choose(cid);
+            trace.accept(cid, None);
+            return trace.choose(cid, "highest priority available");
         }
 
         warn!(
@@ -64,6 +60,29 @@ impl PickingStrategy for PriorityPickStrategy {
             request.role
         );
 
-        no_decision()
+        trace.nothing("no available champion in pool")
+    }
+}
+
+#[async_trait::async_trait]
+impl CandidateSource for PriorityPickStrategy {
+    async fn candidates(
+        &self,
+        request: &BanPickRequest,
+        trace: &mut DecisionTrace,
+    ) -> Vec<Candidate> {
+        let role_prefs = self.prefs.get_for(request.queue_id, &request.role).await;
+
+        ranked(
+            role_prefs
+                .into_iter()
+                .filter(|cid| match request.pick_blocker(*cid) {
+                    Some(reason) => {
+                        trace.reject(*cid, reason);
+                        false
+                    }
+                    None => true,
+                }),
+        )
     }
 }
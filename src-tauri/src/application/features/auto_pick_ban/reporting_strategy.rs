@@ -0,0 +1,42 @@
+use crate::application::features::auto_pick_ban::ban_pick_request::BanPickRequest;
+use crate::domain::champ_select::decision::Decision;
+use crate::domain::champ_select::decision_reporter::DecisionReporter;
+use crate::domain::ports::{BanStrategy, PickingStrategy};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Hands every decision of the wrapped strategy to the reporter.
+pub struct ReportingStrategy<S> {
+    inner: Arc<S>,
+    reporter: Arc<DecisionReporter>,
+}
+
+impl<S> ReportingStrategy<S> {
+    pub fn new(inner: Arc<S>, reporter: Arc<DecisionReporter>) -> Self {
+        Self { inner, reporter }
+    }
+}
+
+#[async_trait]
+impl<S> PickingStrategy for ReportingStrategy<S>
+where
+    S: PickingStrategy + 'static,
+{
+    async fn decide(&self, request: &BanPickRequest) -> Decision {
+        let decision = self.inner.decide(request).await;
+        self.reporter.report("pick", request, &decision);
+        decision
+    }
+}
+
+#[async_trait]
+impl<S> BanStrategy for ReportingStrategy<S>
+where
+    S: BanStrategy + 'static,
+{
+    async fn decide(&self, request: &BanPickRequest) -> Decision {
+        let decision = self.inner.decide(request).await;
+        self.reporter.report("ban", request, &decision);
+        decision
+    }
+}
@@ -0,0 +1,146 @@
+use crate::application::features::auto_pick_ban::ban_pick_request::BanPickRequest;
+use crate::application::features::auto_pick_ban::counter_pick_strategy::CounterPickStrategy;
+use crate::application::features::auto_pick_ban::performance_pick_strategy::PerformancePickStrategy;
+use crate::application::features::auto_pick_ban::priority_pick_strategy::PriorityPickStrategy;
+use crate::application::features::auto_pick_ban::combinators::PipelineStrategy;
+use crate::application::features::auto_pick_ban::scripted_strategy::ScriptedStrategy;
+use crate::domain::champ_select::decision::Decision;
+use crate::domain::ports::{BanStrategy, PickingStrategy};
+use log::info;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PickStrategyKind {
+    Priority,
+    Counter,
+    Script,
+    Performance,
+}
+
+impl PickStrategyKind {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "priority" => Some(Self::Priority),
+            "counter" => Some(Self::Counter),
+            "script" => Some(Self::Script),
+            "performance" => Some(Self::Performance),
+            _ => None,
+        }
+    }
+}
+
+/// What a role is configured to use: a named strategy or a composed pipeline.
+#[derive(Clone)]
+pub enum RoleStrategy {
+    Kind(PickStrategyKind),
+    Pipeline(Arc<PipelineStrategy>),
+}
+
+/// Looks up the role, then the `default` key.
+fn lookup<T: Clone>(map: &HashMap<String, T>, role: &str) -> Option<T> {
+    map.get(&role.to_lowercase())
+        .or_else(|| map.get("default"))
+        .cloned()
+}
+
+fn lowercase_keys<T>(map: HashMap<String, T>) -> HashMap<String, T> {
+    map.into_iter()
+        .map(|(role, v)| (role.to_lowercase(), v))
+        .collect()
+}
+
+/// Dispatches to the picking strategy configured for the request's role.
+/// Roles without an explicit choice use priority order.
+pub struct RoleStrategySelector {
+    priority: Arc<PriorityPickStrategy>,
+    counter: Arc<CounterPickStrategy>,
+    scripted: Arc<ScriptedStrategy>,
+    performance: Arc<PerformancePickStrategy>,
+    strategies: RwLock<HashMap<String, RoleStrategy>>,
+}
+
+impl RoleStrategySelector {
+    pub fn new(
+        priority: Arc<PriorityPickStrategy>,
+        counter: Arc<CounterPickStrategy>,
+        scripted: Arc<ScriptedStrategy>,
+        performance: Arc<PerformancePickStrategy>,
+    ) -> Self {
+        Self {
+            priority,
+            counter,
+            scripted,
+            performance,
+            strategies: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub async fn set_strategies(&self, strategies: HashMap<String, RoleStrategy>) {
+        *self.strategies.write().await = lowercase_keys(strategies);
+    }
+}
+
+#[async_trait::async_trait]
+impl PickingStrategy for RoleStrategySelector {
+    async fn decide(&self, request: &BanPickRequest) -> Decision {
+        let strategy = lookup(&*self.strategies.read().await, &request.role)
+            .unwrap_or(RoleStrategy::Kind(PickStrategyKind::Priority));
+
+        let kind = match strategy {
+            RoleStrategy::Kind(kind) => kind,
+            RoleStrategy::Pipeline(pipeline) => {
+                info!("[RoleStrategySelector] Role={} uses a pipeline", request.role);
+                return PickingStrategy::decide(pipeline.as_ref(), request).await;
+            }
+        };
+
+        info!("[RoleStrategySelector] Role={} uses {:?}", request.role, kind);
+
+        match kind {
+            PickStrategyKind::Priority => self.priority.decide(request).await,
+            PickStrategyKind::Counter => self.counter.decide(request).await,
+            PickStrategyKind::Script => {
+                PickingStrategy::decide(self.scripted.as_ref(), request).await
+            }
+            PickStrategyKind::Performance => self.performance.decide(request).await,
+        }
+    }
+}
+
+/// Dispatches bans to the pipeline configured for the request's role.
+/// Roles without a pipeline go through the scripted strategy, which itself
+/// falls back to priority order when the role has no script.
+pub struct BanStrategySelector {
+    scripted: Arc<ScriptedStrategy>,
+    pipelines: RwLock<HashMap<String, Arc<PipelineStrategy>>>,
+}
+
+impl BanStrategySelector {
+    pub fn new(scripted: Arc<ScriptedStrategy>) -> Self {
+        Self {
+            scripted,
+            pipelines: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub async fn set_pipelines(&self, pipelines: HashMap<String, Arc<PipelineStrategy>>) {
+        *self.pipelines.write().await = lowercase_keys(pipelines);
+    }
+}
+
+#[async_trait::async_trait]
+impl BanStrategy for BanStrategySelector {
+    async fn decide(&self, request: &BanPickRequest) -> Decision {
+        let pipeline = lookup(&*self.pipelines.read().await, &request.role);
+
+        match pipeline {
+            Some(pipeline) => {
+                info!("[BanStrategySelector] Role={} uses a pipeline", request.role);
+                BanStrategy::decide(pipeline.as_ref(), request).await
+            }
+            None => BanStrategy::decide(self.scripted.as_ref(), request).await,
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use crate::application::features::auto_pick_ban::ban_pick_request::BanPickRequest;
+use crate::application::features::auto_pick_ban::champion_preferences::ChampionPreferences;
+use crate::application::features::auto_pick_ban::priority_ban_strategy::PriorityBanStrategy;
+use crate::application::features::auto_pick_ban::priority_pick_strategy::PriorityPickStrategy;
+use crate::domain::champ_select::ban_preferences::BanPreferences;
+use crate::domain::champ_select::decision::{Decision, DecisionTrace};
+use crate::domain::ports::{BanStrategy, ChampionResolverPort, PickingStrategy};
+use async_trait::async_trait;
+use log::{info, warn};
+use rhai::{Array, Dynamic, Engine, Map, Scope, AST};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// Upper bound on Rhai operations per evaluation, so a runaway script cannot stall champ select.
+const MAX_OPERATIONS: u64 = 200_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptSide {
+    Pick,
+    Ban,
+}
+
+enum ScriptOutcome {
+    NoScript,
+    Chose(i32),
+    Nothing,
+    Failed(String),
+}
+
+/// Evaluates a user-supplied Rhai script per role to pick or ban.
+///
+/// The script sees a `req` map (role, queue_id, banned, my_team, their_team,
+/// intended_picks, my_pick, lane_opponent, pool) where every champion is a
+/// `#{id, name, tags}` map, and returns a champion id, a champion name or `()`.
+/// Missing scripts and script errors fall back to the priority strategies.
+pub struct ScriptedStrategy {
+    engine: Engine,
+    pick_scripts: RwLock<HashMap<String, AST>>,
+    ban_scripts: RwLock<HashMap<String, AST>>,
+
+    pick_prefs: Arc<ChampionPreferences>,
+    ban_prefs: Arc<BanPreferences>,
+    resolver: Arc<dyn ChampionResolverPort>,
+
+    pick_fallback: Arc<PriorityPickStrategy>,
+    ban_fallback: Arc<PriorityBanStrategy>,
+}
+
+impl ScriptedStrategy {
+    pub fn new(
+        pick_prefs: Arc<ChampionPreferences>,
+        ban_prefs: Arc<BanPreferences>,
+        resolver: Arc<dyn ChampionResolverPort>,
+        pick_fallback: Arc<PriorityPickStrategy>,
+        ban_fallback: Arc<PriorityBanStrategy>,
+    ) -> Self {
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_OPERATIONS);
+        engine.set_max_call_levels(32);
+        engine.set_max_expr_depths(64, 32);
+        engine.set_max_string_size(10_000);
+        engine.set_max_array_size(1_000);
+        engine.set_max_map_size(1_000);
+        engine.disable_symbol("eval");
+        engine.on_print(|s| info!("[ScriptedStrategy] print: {s}"));
+        engine.on_debug(|s, _, pos| info!("[ScriptedStrategy] debug {pos}: {s}"));
+
+        Self {
+            engine,
+            pick_scripts: RwLock::new(HashMap::new()),
+            ban_scripts: RwLock::new(HashMap::new()),
+            pick_prefs,
+            ban_prefs,
+            resolver,
+            pick_fallback,
+            ban_fallback,
+        }
+    }
+
+    fn scripts(&self, side: ScriptSide) -> &RwLock<HashMap<String, AST>> {
+        match side {
+            ScriptSide::Pick => &self.pick_scripts,
+            ScriptSide::Ban => &self.ban_scripts,
+        }
+    }
+
+    /// Compiles role → source scripts once; scripts that fail to compile are dropped.
+    pub fn compile_scripts(&self, side: ScriptSide, sources: HashMap<String, String>) {
+        let mut compiled = HashMap::new();
+
+        for (role, source) in sources {
+            if source.trim().is_empty() {
+                continue;
+            }
+
+            match self.engine.compile(&source) {
+                Ok(ast) => {
+                    compiled.insert(role.to_lowercase(), ast);
+                }
+                Err(e) => warn!(
+                    "[ScriptedStrategy] {:?} script for role={} does not compile: {}",
+                    side, role, e
+                ),
+            }
+        }
+
+        *self.scripts(side).write().unwrap() = compiled;
+    }
+
+    async fn champion(&self, cid: i32) -> Dynamic {
+        let mut m = Map::new();
+        m.insert("id".into(), Dynamic::from(cid as i64));
+
+        let name = self.resolver.resolve_name(cid).await.unwrap_or_default();
+        m.insert("name".into(), Dynamic::from(name));
+
+        let tags: Array = self
+            .resolver
+            .resolve_tags(cid)
+            .await
+            .into_iter()
+            .map(Dynamic::from)
+            .collect();
+        m.insert("tags".into(), Dynamic::from_array(tags));
+
+        Dynamic::from_map(m)
+    }
+
+    async fn champions(&self, ids: &[i32]) -> Dynamic {
+        let mut out = Array::new();
+        for cid in ids {
+            out.push(self.champion(*cid).await);
+        }
+        Dynamic::from_array(out)
+    }
+
+    async fn optional_champion(&self, cid: Option<i32>) -> Dynamic {
+        match cid {
+            Some(cid) => self.champion(cid).await,
+            None => Dynamic::UNIT,
+        }
+    }
+
+    async fn build_request(&self, req: &BanPickRequest, pool: &[i32]) -> Map {
+        let mut m = Map::new();
+        m.insert("role".into(), Dynamic::from(req.role.clone()));
+        m.insert("queue_id".into(), Dynamic::from(req.queue_id));
+        m.insert("banned".into(), self.champions(&req.banned).await);
+        m.insert("my_team".into(), self.champions(&req.my_team).await);
+        m.insert("their_team".into(), self.champions(&req.their_team).await);
+        m.insert(
+            "intended_picks".into(),
+            self.champions(&req.intended_picks).await,
+        );
+        m.insert("my_pick".into(), self.optional_champion(req.my_pick).await);
+        m.insert(
+            "lane_opponent".into(),
+            self.optional_champion(req.lane_opponent()).await,
+        );
+        m.insert("pool".into(), self.champions(pool).await);
+        m
+    }
+
+    async fn run(&self, side: ScriptSide, req: &BanPickRequest) -> ScriptOutcome {
+        let role = req.role.to_lowercase();
+
+        let Some(ast) = self.scripts(side).read().unwrap().get(&role).cloned() else {
+            return ScriptOutcome::NoScript;
+        };
+
+        let pool = match side {
+            ScriptSide::Pick => self.pick_prefs.get_for(req.queue_id, &role).await,
+            ScriptSide::Ban => self.ban_prefs.get_for(req.queue_id, &role).await,
+        };
+
+        let mut scope = Scope::new();
+        scope.push("req", self.build_request(req, &pool).await);
+
+        let result = match self.engine.eval_ast_with_scope::<Dynamic>(&mut scope, &ast) {
+            Ok(r) => r,
+            Err(e) => return ScriptOutcome::Failed(e.to_string()),
+        };
+
+        let cid = if result.is_unit() {
+            return ScriptOutcome::Nothing;
+        } else if let Ok(id) = result.as_int() {
+            id as i32
+        } else if let Ok(name) = result.clone().into_string() {
+            match self.resolver.resolve_id(&name).await {
+                Some(id) => id,
+                None => return ScriptOutcome::Failed(format!("unknown champion '{name}'")),
+            }
+        } else {
+            return ScriptOutcome::Failed(format!("unexpected return type {}", result.type_name()));
+        };
+
+        let blocker = match side {
+            ScriptSide::Pick => req.pick_blocker(cid),
+            ScriptSide::Ban => req.ban_blocker(cid),
+        };
+
+        match blocker {
+            Some(reason) => ScriptOutcome::Failed(format!("chose {cid} which is {reason}")),
+            None => ScriptOutcome::Chose(cid),
+        }
+    }
+
+    async fn fallback(&self, side: ScriptSide, req: &BanPickRequest) -> Decision {
+        match side {
+            ScriptSide::Pick => self.pick_fallback.decide(req).await,
+            ScriptSide::Ban => self.ban_fallback.decide(req).await,
+        }
+    }
+
+    async fn decide_for(&self, side: ScriptSide, req: &BanPickRequest) -> Decision {
+        match self.run(side, req).await {
+            ScriptOutcome::Chose(cid) => {
+                info!(
+                    "[ScriptedStrategy] ✔ {:?} script chose champion_id={cid}",
+                    side
+                );
+                let mut trace = DecisionTrace::new("script");
+                trace.accept(cid, None);
+                trace.choose(cid, "script returned a champion")
+            }
+            ScriptOutcome::Nothing => {
+                info!("[ScriptedStrategy] {:?} script returned nothing", side);
+                DecisionTrace::new("script").nothing("script returned nothing")
+            }
+            ScriptOutcome::NoScript => self
+                .fallback(side, req)
+                .await
+                .via("script", "no script for role"),
+            ScriptOutcome::Failed(e) => {
+                warn!(
+                    "[ScriptedStrategy] {:?} script failed for role={}: {} — using priority",
+                    side, req.role, e
+                );
+                self.fallback(side, req)
+                    .await
+                    .via("script", &format!("script failed: {e}"))
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl PickingStrategy for ScriptedStrategy {
+    async fn decide(&self, request: &BanPickRequest) -> Decision {
+        self.decide_for(ScriptSide::Pick, request).await
+    }
+}
+
+#[async_trait]
+impl BanStrategy for ScriptedStrategy {
+    async fn decide(&self, request: &BanPickRequest) -> Decision {
+        self.decide_for(ScriptSide::Ban, request).await
+    }
+}
@@ -0,0 +1,191 @@
+use crate::application::features::auto_pick_ban::combinators::{
+    ChampionFilter, FallbackSource, FilterSource, PipelineStrategy, RandomTopSource, ScoreSource,
+    Scorer,
+};
+use crate::application::features::auto_pick_ban::counter_pick_strategy::CounterPickStrategy;
+use crate::application::features::auto_pick_ban::performance_pick_strategy::PerformancePickStrategy;
+use crate::application::features::auto_pick_ban::priority_ban_strategy::PriorityBanStrategy;
+use crate::application::features::auto_pick_ban::priority_pick_strategy::PriorityPickStrategy;
+use crate::domain::champ_select::matchup_table::MatchupTable;
+use crate::domain::match_stats::MatchStats;
+use crate::domain::ports::{CandidateSource, ChampionResolverPort};
+use serde::Deserialize;
+use serde_json::Value;
+use std::sync::Arc;
+
+/// Declarative pipeline, e.g. "counter-pick, else random of my top 3":
+///
+/// ```json
+/// { "type": "fallback", "steps": [
+///     { "type": "counter" },
+///     { "type": "randomTop", "n": 3, "source": { "type": "priority" } }
+/// ] }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum PipelineNode {
+    Priority,
+    Counter,
+    Performance,
+    Fallback {
+        steps: Vec<PipelineNode>,
+    },
+    ExcludeUnowned {
+        source: Box<PipelineNode>,
+    },
+    ExcludeTags {
+        tags: Vec<String>,
+        source: Box<PipelineNode>,
+    },
+    Score {
+        scorer: ScorerKind,
+        #[serde(default = "default_weight")]
+        weight: f64,
+        source: Box<PipelineNode>,
+    },
+    RandomTop {
+        n: usize,
+        #[serde(default)]
+        weights: Option<Vec<f64>>,
+        source: Box<PipelineNode>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ScorerKind {
+    Matchup,
+    Performance,
+}
+
+fn default_weight() -> f64 {
+    1.0
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PipelineSide {
+    Pick,
+    Ban,
+}
+
+/// Builds candidate pipelines out of the shared strategies and ports.
+pub struct PipelineBuilder {
+    priority_pick: Arc<PriorityPickStrategy>,
+    priority_ban: Arc<PriorityBanStrategy>,
+    counter: Arc<CounterPickStrategy>,
+    performance: Arc<PerformancePickStrategy>,
+    resolver: Arc<dyn ChampionResolverPort>,
+    matchups: Arc<MatchupTable>,
+    stats: Arc<MatchStats>,
+}
+
+impl PipelineBuilder {
+    pub fn new(
+        priority_pick: Arc<PriorityPickStrategy>,
+        priority_ban: Arc<PriorityBanStrategy>,
+        counter: Arc<CounterPickStrategy>,
+        performance: Arc<PerformancePickStrategy>,
+        resolver: Arc<dyn ChampionResolverPort>,
+        matchups: Arc<MatchupTable>,
+        stats: Arc<MatchStats>,
+    ) -> Self {
+        Self {
+            priority_pick,
+            priority_ban,
+            counter,
+            performance,
+            resolver,
+            matchups,
+            stats,
+        }
+    }
+
+    pub fn parse(&self, side: PipelineSide, val: &Value) -> Result<Arc<PipelineStrategy>, String> {
+        let node: PipelineNode =
+            serde_json::from_value(val.clone()).map_err(|e| format!("invalid pipeline: {e}"))?;
+
+        Ok(Arc::new(PipelineStrategy::new(self.build(side, &node)?)))
+    }
+
+    pub fn build(
+        &self,
+        side: PipelineSide,
+        node: &PipelineNode,
+    ) -> Result<Arc<dyn CandidateSource>, String> {
+        let source: Arc<dyn CandidateSource> = match node {
+            PipelineNode::Priority => match side {
+                PipelineSide::Pick => self.priority_pick.clone(),
+                PipelineSide::Ban => self.priority_ban.clone(),
+            },
+
+            PipelineNode::Counter => match side {
+                PipelineSide::Pick => self.counter.clone(),
+                PipelineSide::Ban => return Err("counter is only available for picks".into()),
+            },
+
+            PipelineNode::Performance => match side {
+                PipelineSide::Pick => self.performance.clone(),
+                PipelineSide::Ban => return Err("performance is only available for picks".into()),
+            },
+
+            PipelineNode::Fallback { steps } => {
+                if steps.is_empty() {
+                    return Err("fallback needs at least one step".into());
+                }
+
+                let steps = steps
+                    .iter()
+                    .map(|s| self.build(side, s))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Arc::new(FallbackSource::new(steps))
+            }
+
+            PipelineNode::ExcludeUnowned { source } => Arc::new(FilterSource::new(
+                self.build(side, source)?,
+                ChampionFilter::ExcludeUnowned,
+            )),
+
+            PipelineNode::ExcludeTags { tags, source } => Arc::new(FilterSource::new(
+                self.build(side, source)?,
+                ChampionFilter::ExcludeTags {
+                    tags: tags.clone(),
+                    resolver: self.resolver.clone(),
+                },
+            )),
+
+            PipelineNode::Score {
+                scorer,
+                weight,
+                source,
+            } => {
+                let scorer = match scorer {
+                    ScorerKind::Matchup => Scorer::Matchup(self.matchups.clone()),
+                    ScorerKind::Performance => Scorer::Performance(self.stats.clone()),
+                };
+
+                Arc::new(ScoreSource::new(self.build(side, source)?, scorer, *weight))
+            }
+
+            PipelineNode::RandomTop { n, weights, source } => {
+                if *n == 0 {
+                    return Err("randomTop needs n > 0".into());
+                }
+
+                if let Some(w) = weights {
+                    if w.iter().any(|x| !x.is_finite() || *x < 0.0) {
+                        return Err("randomTop weights must be non-negative".into());
+                    }
+                }
+
+                Arc::new(RandomTopSource::new(
+                    self.build(side, source)?,
+                    *n,
+                    weights.clone(),
+                ))
+            }
+        };
+
+        Ok(source)
+    }
+}
@@ -1,30 +1,137 @@
 use log::info;
+use serde_json::json;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::task;
 
+use crate::application::features::auto_pick_ban::lock_scheduler::LockScheduler;
+use crate::domain::delay_state::DelayState;
 use crate::domain::events::{EventType, LeagueEvent};
-use crate::domain::ports::{BannerPort, HovererPort, LeagueEventSubscriber, PickerPort};
+use crate::domain::log_entry::LogEntry;
+use crate::domain::role_resolver::RoleResolver;
+use crate::domain::ports::{
+    BannerPort, HovererPort, LeagueEventSubscriber, LogPublisherPort, PickerPort,
+    UiEventPublisherPort,
+};
 
 use crate::domain::champ_select::models::{ChampSelectSessionPayload, CsAction};
+use crate::domain::champ_select::session_control::{ManualOverride, SessionControl};
 
 pub struct AutoPickBanSubscriber {
     hoverer: Arc<dyn HovererPort>,
     picker: Arc<dyn PickerPort>,
     banner: Arc<dyn BannerPort>,
+    /// Time left in the phase at which to lock; 0 locks as soon as the pick is up.
+    lock_ms_left: Arc<DelayState>,
+    scheduler: LockScheduler,
+    control: Arc<SessionControl>,
+    log_pub: Arc<dyn LogPublisherPort>,
+    ui_pub: Arc<dyn UiEventPublisherPort>,
+    roles: Arc<RoleResolver>,
 }
 
 impl AutoPickBanSubscriber {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         hoverer: Arc<dyn HovererPort>,
         picker: Arc<dyn PickerPort>,
         banner: Arc<dyn BannerPort>,
+        lock_ms_left: Arc<DelayState>,
+        control: Arc<SessionControl>,
+        log_pub: Arc<dyn LogPublisherPort>,
+        ui_pub: Arc<dyn UiEventPublisherPort>,
+        roles: Arc<RoleResolver>,
     ) -> Arc<Self> {
         Arc::new(Self {
             hoverer,
             picker,
             banner,
+            lock_ms_left,
+            scheduler: LockScheduler::new(),
+            control,
+            log_pub,
+            ui_pub,
+            roles,
         })
     }
+
+    fn announce_override(&self, o: &ManualOverride) {
+        match o.expected {
+            Some(expected) => info!(
+                "[APB] ⚠ Action {} shows champion {} instead of {}, handing control to the user",
+                o.action_id, o.actual, expected
+            ),
+            None => info!(
+                "[APB] ⚠ Action {} shows champion {} chosen by hand, handing control to the user",
+                o.action_id, o.actual
+            ),
+        }
+
+        let payload = json!({
+            "gameId": o.game_id,
+            "userControlled": true,
+            "actionId": o.action_id,
+            "expectedChampionId": o.expected,
+            "actualChampionId": o.actual,
+        });
+
+        self.log_pub.publish(LogEntry {
+            source: "autopickban".into(),
+            payload: payload.clone(),
+        });
+        self.ui_pub.emit("champ-select-control", payload);
+    }
+
+    /// Hovers now and schedules the lock for when `lock_ms_left` remains.
+    /// Every session update reschedules, so the lock always decides on the latest state.
+    fn hover_then_lock(
+        &self,
+        session: Arc<ChampSelectSessionPayload>,
+        role: String,
+        lock_ms_left: u64,
+    ) {
+        let time_left = session.timer.adjustedTimeLeftInPhase.unwrap_or(0).max(0) as u64;
+        let delay = time_left.saturating_sub(lock_ms_left);
+
+        info!(
+            "[APB] Hovering now, locking in {} ms ({} ms left in phase)",
+            delay, time_left
+        );
+
+        let hoverer = Arc::clone(&self.hoverer);
+        let hover_session = Arc::clone(&session);
+        let hover_role = role.clone();
+
+        task::spawn(async move {
+            hoverer.hover(&hover_session, &hover_role).await;
+        });
+
+        let picker = Arc::clone(&self.picker);
+
+        self.scheduler
+            .schedule(Duration::from_millis(delay), async move {
+                let Some(action) = find_active_action(&session) else {
+                    return;
+                };
+
+                info!("[APB] Lock timer fired, calling picker.pick()");
+                picker.pick(&session, action, &role).await;
+            });
+    }
+}
+
+/// Role for our in-progress pick, if the session is waiting on one.
+fn pending_pick_role(s: &ChampSelectSessionPayload, roles: &RoleResolver) -> Option<String> {
+    if s.timer.phase != "BAN_PICK" {
+        return None;
+    }
+
+    let action = find_active_action(s)?;
+    if action.action_type != "pick" {
+        return None;
+    }
+
+    roles.resolve(s)
 }
 
 impl LeagueEventSubscriber for AutoPickBanSubscriber {
@@ -40,9 +147,31 @@ impl LeagueEventSubscriber for AutoPickBanSubscriber {
             info!("[APB] Failed to parse ChampSelectSession");
             return;
         };
+
+        if let Some(o) = self.control.observe(&session) {
+            self.announce_override(&o);
+        }
+
+        if self.control.is_user_controlled(session.gameId) {
+            self.scheduler.cancel();
+            return;
+        }
+
+        let lock_ms_left = self.lock_ms_left.get();
+        if lock_ms_left > 0 {
+            if let Some(role) = pending_pick_role(&session, &self.roles) {
+                self.hover_then_lock(Arc::new(session), role, lock_ms_left);
+                return;
+            }
+        }
+
+        // Not (or no longer) our pick: whatever lock was pending is stale.
+        self.scheduler.cancel();
+
         let hoverer = Arc::clone(&self.hoverer);
         let picker = Arc::clone(&self.picker);
         let banner = Arc::clone(&self.banner);
+        let roles = Arc::clone(&self.roles);
 
         task::spawn(async move {
             let phase = session.timer.phase.as_str();
@@ -52,7 +181,7 @@ impl LeagueEventSubscriber for AutoPickBanSubscriber {
                 "PLANNING" => {
                     info!("[APB] In PLANNING phase");
 
-                    if let Some(role) = resolve_role(&session) {
+                    if let Some(role) = roles.resolve(&session) {
                         info!("[APB] Role = {}", role);
                         info!("[APB] Calling hoverer.hover()");
                         let _ = hoverer.hover(&session, &role).await;
@@ -67,7 +196,7 @@ impl LeagueEventSubscriber for AutoPickBanSubscriber {
                     if let Some(action) = find_active_action(&session) {
                         info!("[APB] Active action type = {}", action.action_type);
 
-                        if let Some(role) = resolve_role(&session) {
+                        if let Some(role) = roles.resolve(&session) {
                             info!("[APB] Role = {}", role);
 
                             match action.action_type.as_str() {
@@ -78,6 +207,9 @@ impl LeagueEventSubscriber for AutoPickBanSubscriber {
                                 "ban" => {
                                     info!("[APB] Calling banner.ban()");
                                     let _ = banner.ban(&session, action, &role).await;
+
+                                    info!("[APB] Refreshing pick intent");
+                                    hoverer.hover(&session, &role).await;
                                 }
                                 other => {
                                     info!("[APB] Unknown action type {}", other);
@@ -86,8 +218,12 @@ impl LeagueEventSubscriber for AutoPickBanSubscriber {
                         } else {
                             info!("[APB] No role resolved");
                         }
+                    } else if let Some(role) = roles.resolve(&session) {
+                        // Not our turn: keep the pick intent valid as bans and picks land.
+                        info!("[APB] No active action, refreshing pick intent");
+                        hoverer.hover(&session, &role).await;
                     } else {
-                        info!("[APB] No active action found");
+                        info!("[APB] No active action and no role resolved");
                     }
                 }
 
@@ -111,18 +247,3 @@ fn find_active_action(s: &ChampSelectSessionPayload) -> Option<&CsAction> {
     }
     None
 }
-
-fn resolve_role(s: &ChampSelectSessionPayload) -> Option<String> {
-    s.myTeam
-        .iter()
-        .find(|p| p.cellId == s.localPlayerCellId)
-        .and_then(|p| {
-            let role = p.assignedPosition.clone()?.to_lowercase();
-            match role.as_str() {
-                "" => None,
-                "utility" => Some("support".into()),
-                "middle" => Some("mid".into()),
-                _ => Some(role),
-            }
-        })
-}
@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::ports::ChampionResolverPort;
+use log::info;
+
+/// Key used for queues that have no list of their own.
+const DEFAULT_KEY: &str = "default";
+
+/// Bench priority lists keyed by queue id (as a string) or `"default"`.
+pub struct BenchPreferences {
+    data: RwLock<HashMap<String, Vec<i32>>>,
+}
+
+impl BenchPreferences {
+    pub fn new() -> Self {
+        Self {
+            data: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub async fn get_for_queue(&self, queue_id: i64) -> Vec<i32> {
+        let g = self.data.read().await;
+        g.get(&queue_id.to_string())
+            .or_else(|| g.get(DEFAULT_KEY))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub async fn resolve_from_names(
+        &self,
+        queue_map: HashMap<String, Vec<String>>,
+        resolver: Arc<dyn ChampionResolverPort>,
+    ) {
+        let mut resolved: HashMap<String, Vec<i32>> = HashMap::new();
+
+        for (queue, names) in queue_map {
+            let mut list = Vec::new();
+
+            for name in names {
+                if let Some(id) = resolver.resolve_id(&name).await {
+                    list.push(id);
+                } else {
+                    info!("⚠ Unknown champion in bench priorities: {}", name);
+                }
+            }
+
+            resolved.insert(queue.to_lowercase(), list);
+        }
+
+        let mut g = self.data.write().await;
+        *g = resolved;
+    }
+}
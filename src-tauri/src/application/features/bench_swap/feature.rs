@@ -0,0 +1,93 @@
+use crate::application::features::bench_swap::bench_preferences::BenchPreferences;
+use crate::application::features::bench_swap::subscriber::BenchSwapSubscriber;
+use crate::domain::delay_state::DelayState;
+use crate::domain::feature::{Feature, FeatureId};
+use crate::domain::ports::{
+    ChampionResolverPort, LcuApiPort, LeagueEventPublisherPort, LeagueEventSubscriber,
+};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub struct BenchSwapFeature {
+    league_pub: Arc<dyn LeagueEventPublisherPort>,
+    subscriber: Arc<dyn LeagueEventSubscriber>,
+    delay: Arc<DelayState>,
+
+    prefs: Arc<BenchPreferences>,
+    resolver: Arc<dyn ChampionResolverPort>,
+}
+
+impl BenchSwapFeature {
+    pub fn new(
+        league_pub: Arc<dyn LeagueEventPublisherPort>,
+        api: Arc<dyn LcuApiPort>,
+        resolver: Arc<dyn ChampionResolverPort>,
+    ) -> Self {
+        let delay = Arc::new(DelayState::new());
+        let prefs = Arc::new(BenchPreferences::new());
+        let subscriber = BenchSwapSubscriber::new(api, delay.clone(), prefs.clone());
+
+        Self {
+            league_pub,
+            subscriber,
+            delay,
+            prefs,
+            resolver,
+        }
+    }
+
+    fn apply_priority_settings(&self, val: &Value) {
+        let Some(obj) = val.as_object() else {
+            return;
+        };
+
+        let mut queue_to_names = HashMap::<String, Vec<String>>::new();
+
+        for (queue, arr) in obj {
+            let names = arr
+                .as_array()
+                .unwrap_or(&vec![])
+                .iter()
+                .filter_map(|v| v.as_str().map(|x| x.to_string()))
+                .collect::<Vec<_>>();
+
+            queue_to_names.insert(queue.clone(), names);
+        }
+
+        let prefs = self.prefs.clone();
+        let resolver = self.resolver.clone();
+
+        tauri::async_runtime::spawn(async move {
+            prefs.resolve_from_names(queue_to_names, resolver).await;
+        });
+    }
+}
+
+impl Feature for BenchSwapFeature {
+    fn id(&self) -> FeatureId {
+        FeatureId::BenchSwap
+    }
+
+    fn display_name(&self) -> String {
+        "Bench Auto-Swap".into()
+    }
+
+    fn enable(&self) {
+        self.league_pub.subscribe(self.subscriber.clone());
+    }
+
+    fn disable(&self) {
+        self.league_pub.unsubscribe(&self.subscriber);
+    }
+
+    fn configure(&self, settings: Value) {
+        if let Some(v) = settings.get("delayMs").and_then(|v| v.as_u64()) {
+            self.delay.set(v);
+        }
+
+        if let Some(p) = settings.get("benchPriorities") {
+            self.apply_priority_settings(p);
+        }
+    }
+}
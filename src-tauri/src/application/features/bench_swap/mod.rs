@@ -0,0 +1,3 @@
+pub mod bench_preferences;
+pub mod feature;
+pub mod subscriber;
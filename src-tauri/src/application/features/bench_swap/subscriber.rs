@@ -0,0 +1,114 @@
+use log::info;
+use std::sync::{Arc, Mutex};
+use tokio::task;
+use tokio::time::{sleep, Duration};
+
+use crate::application::features::bench_swap::bench_preferences::BenchPreferences;
+use crate::domain::champ_select::models::ChampSelectSessionPayload;
+use crate::domain::delay_state::DelayState;
+use crate::domain::events::{EventType, LeagueEvent};
+use crate::domain::ports::{LcuApiPort, LeagueEventSubscriber};
+
+pub struct BenchSwapSubscriber {
+    api: Arc<dyn LcuApiPort>,
+    delay: Arc<DelayState>,
+    prefs: Arc<BenchPreferences>,
+    /// Bench champion a swap is currently scheduled for.
+    pending: Arc<Mutex<Option<i64>>>,
+}
+
+impl BenchSwapSubscriber {
+    pub fn new(
+        api: Arc<dyn LcuApiPort>,
+        delay: Arc<DelayState>,
+        prefs: Arc<BenchPreferences>,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            api,
+            delay,
+            prefs,
+            pending: Arc::new(Mutex::new(None)),
+        })
+    }
+}
+
+impl LeagueEventSubscriber for BenchSwapSubscriber {
+    fn on_event(&self, event: &LeagueEvent) {
+        if event.event_type != EventType::ChampSelectSession {
+            return;
+        }
+
+        let source = event.data.get("payload").unwrap_or(&event.data);
+
+        let Ok(session) = serde_json::from_value::<ChampSelectSessionPayload>(source.clone())
+        else {
+            info!("[BenchSwap] Failed to parse ChampSelectSession");
+            return;
+        };
+
+        if !session.benchEnabled || session.benchChampions.is_empty() {
+            return;
+        }
+
+        let api = self.api.clone();
+        let prefs = self.prefs.clone();
+        let pending = self.pending.clone();
+        let delay = self.delay.get();
+
+        task::spawn(async move {
+            let priorities = prefs.get_for_queue(session.queueId).await;
+
+            let Some(target) = best_swap(&session, &priorities) else {
+                return;
+            };
+
+            {
+                let mut guard = pending.lock().unwrap();
+                if guard.is_some() {
+                    return;
+                }
+                *guard = Some(target);
+            }
+
+            info!("[BenchSwap] Swapping to {} in {} ms", target, delay);
+            sleep(Duration::from_millis(delay)).await;
+
+            // The bench may have changed while we waited; decide again on fresh state.
+            let fresh = api
+                .get("/lol-champ-select/v1/session")
+                .await
+                .ok()
+                .and_then(|v| serde_json::from_value::<ChampSelectSessionPayload>(v).ok());
+
+            let target = fresh.as_ref().and_then(|s| best_swap(s, &priorities));
+
+            if let Some(target) = target {
+                let path = format!("/lol-champ-select/v1/session/bench/swap/{}", target);
+                match api.post(&path, None).await {
+                    Ok(_) => info!("[BenchSwap] Swapped to champion {}", target),
+                    Err(e) => info!("[BenchSwap] Swap to {} failed: {:?}", target, e),
+                }
+            } else {
+                info!("[BenchSwap] Swap no longer worthwhile, skipping");
+            }
+
+            *pending.lock().unwrap() = None;
+        });
+    }
+}
+
+/// Highest-ranked bench champion that ranks strictly above the one we hold.
+fn best_swap(session: &ChampSelectSessionPayload, priorities: &[i32]) -> Option<i64> {
+    let rank = |cid: i64| priorities.iter().position(|p| *p as i64 == cid);
+
+    let current = session.local_player().map(|p| p.championId).unwrap_or(0);
+    let current_rank = rank(current).unwrap_or(usize::MAX);
+
+    session
+        .benchChampions
+        .iter()
+        .filter_map(|b| rank(b.championId).map(|r| (r, b.championId)))
+        .filter(|(r, _)| *r < current_rank)
+        .min_by_key(|(r, _)| *r)
+        .map(|(_, cid)| cid)
+}
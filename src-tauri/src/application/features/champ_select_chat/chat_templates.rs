@@ -0,0 +1,100 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+/// Message templates per trigger. Placeholders: `{role}`, `{queue}`,
+/// `{champion}`, `{opponent}` and `{runePage}`; unknown ones are left as typed.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatTemplates {
+    /// Sent once when champ select starts, e.g. "{role}".
+    pub session_start: Option<String>,
+    /// Sent when the local player's pick intent changes, e.g. "going {champion}".
+    pub intended_pick: Option<String>,
+    /// Sent once in finalization, e.g. "runes: {runePage}".
+    pub rune_page: Option<String>,
+}
+
+pub struct TemplateStore {
+    templates: RwLock<ChatTemplates>,
+}
+
+impl TemplateStore {
+    pub fn new() -> Self {
+        Self {
+            templates: RwLock::new(ChatTemplates::default()),
+        }
+    }
+
+    pub fn get(&self) -> ChatTemplates {
+        self.templates.read().unwrap().clone()
+    }
+
+    pub fn set(&self, templates: ChatTemplates) {
+        *self.templates.write().unwrap() = templates;
+    }
+}
+
+pub fn render(template: &str, values: &HashMap<&str, String>) -> String {
+    values
+        .iter()
+        .fold(template.to_string(), |out, (key, value)| {
+            out.replace(&format!("{{{key}}}"), value)
+        })
+}
+
+/// Caps messages per champ select and spaces them out.
+pub struct ChatRateLimit {
+    min_interval_ms: AtomicU64,
+    max_per_session: AtomicU64,
+    /// (game id, messages sent in it, time of the last one)
+    sent: Mutex<(i64, u64, Option<Instant>)>,
+}
+
+impl ChatRateLimit {
+    pub fn new() -> Self {
+        Self {
+            min_interval_ms: AtomicU64::new(5_000),
+            max_per_session: AtomicU64::new(3),
+            sent: Mutex::new((0, 0, None)),
+        }
+    }
+
+    pub fn configure(&self, min_interval_ms: Option<u64>, max_per_session: Option<u64>) {
+        if let Some(v) = min_interval_ms {
+            self.min_interval_ms.store(v, Ordering::Relaxed);
+        }
+        if let Some(v) = max_per_session {
+            self.max_per_session.store(v, Ordering::Relaxed);
+        }
+    }
+
+    /// Takes a slot for one message, or explains why there is none.
+    pub fn try_acquire(&self, game_id: i64) -> Result<(), String> {
+        let mut sent = self.sent.lock().unwrap();
+        if sent.0 != game_id {
+            *sent = (game_id, 0, None);
+        }
+
+        let max = self.max_per_session.load(Ordering::Relaxed);
+        if sent.1 >= max {
+            return Err(format!("already sent {max} message(s) this champ select"));
+        }
+
+        let interval = Duration::from_millis(self.min_interval_ms.load(Ordering::Relaxed));
+        if let Some(last) = sent.2 {
+            if last.elapsed() < interval {
+                return Err(format!(
+                    "last message was under {} ms ago",
+                    interval.as_millis()
+                ));
+            }
+        }
+
+        sent.1 += 1;
+        sent.2 = Some(Instant::now());
+        Ok(())
+    }
+}
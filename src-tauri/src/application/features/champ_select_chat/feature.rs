@@ -0,0 +1,80 @@
+use crate::application::features::champ_select_chat::chat_templates::{
+    ChatRateLimit, ChatTemplates, TemplateStore,
+};
+use crate::application::features::champ_select_chat::subscriber::ChampSelectChatSubscriber;
+use crate::domain::feature::{Feature, FeatureId};
+use crate::domain::ports::{
+    ChampionResolverPort, ChatPort, LcuApiPort, LeagueEventPublisherPort, LeagueEventSubscriber,
+};
+use crate::domain::role_resolver::RoleResolver;
+use log::info;
+use serde_json::Value;
+use std::sync::Arc;
+
+pub struct ChampSelectChatFeature {
+    league_pub: Arc<dyn LeagueEventPublisherPort>,
+    subscriber: Arc<dyn LeagueEventSubscriber>,
+
+    templates: Arc<TemplateStore>,
+    limit: Arc<ChatRateLimit>,
+}
+
+impl ChampSelectChatFeature {
+    pub fn new(
+        league_pub: Arc<dyn LeagueEventPublisherPort>,
+        api: Arc<dyn LcuApiPort>,
+        chat: Arc<dyn ChatPort>,
+        resolver: Arc<dyn ChampionResolverPort>,
+        roles: Arc<RoleResolver>,
+    ) -> Self {
+        let templates = Arc::new(TemplateStore::new());
+        let limit = Arc::new(ChatRateLimit::new());
+        let subscriber = ChampSelectChatSubscriber::new(
+            api,
+            chat,
+            resolver,
+            roles,
+            templates.clone(),
+            limit.clone(),
+        );
+
+        Self {
+            league_pub,
+            subscriber,
+            templates,
+            limit,
+        }
+    }
+}
+
+impl Feature for ChampSelectChatFeature {
+    fn id(&self) -> FeatureId {
+        FeatureId::ChampSelectChat
+    }
+
+    fn display_name(&self) -> String {
+        "Champ Select Chat".into()
+    }
+
+    fn enable(&self) {
+        self.league_pub.subscribe(self.subscriber.clone());
+    }
+
+    fn disable(&self) {
+        self.league_pub.unsubscribe(&self.subscriber);
+    }
+
+    fn configure(&self, settings: Value) {
+        if let Some(t) = settings.get("templates") {
+            match serde_json::from_value::<ChatTemplates>(t.clone()) {
+                Ok(t) => self.templates.set(t),
+                Err(e) => info!("⚠ Invalid chat templates: {}", e),
+            }
+        }
+
+        self.limit.configure(
+            settings.get("minIntervalMs").and_then(|v| v.as_u64()),
+            settings.get("maxPerSession").and_then(|v| v.as_u64()),
+        );
+    }
+}
@@ -0,0 +1,3 @@
+pub mod chat_templates;
+pub mod feature;
+pub mod subscriber;
@@ -0,0 +1,254 @@
+use log::info;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task;
+use tokio::time::sleep;
+
+use crate::application::features::auto_pick_ban::ban_pick_request::BanPickRequest;
+use crate::application::features::champ_select_chat::chat_templates::{
+    render, ChatRateLimit, TemplateStore,
+};
+use crate::domain::champ_select::models::ChampSelectSessionPayload;
+use crate::domain::events::{EventType, LeagueEvent};
+use crate::domain::ports::{ChampionResolverPort, ChatPort, LcuApiPort, LeagueEventSubscriber};
+use crate::domain::role_resolver::RoleResolver;
+
+/// The rune page is announced this long before finalization ends, once the
+/// rune picker (and the user) have settled on it.
+const RUNE_PAGE_LEAD_MS: i64 = 5_000;
+
+#[derive(Debug, Clone, Copy)]
+enum Trigger {
+    SessionStart,
+    IntendedPick(i64),
+    RunePage,
+}
+
+/// What has already been announced in the current champ select.
+#[derive(Default)]
+struct Announced {
+    game_id: i64,
+    started: bool,
+    pick: Option<i64>,
+    /// A pick being announced right now, so later updates do not repeat it.
+    pick_pending: Option<i64>,
+    rune_page: bool,
+}
+
+impl Announced {
+    /// Records how a trigger went. A pick only counts as announced once
+    /// sent, and an unsent call-out or rune page is tried again on a later update.
+    fn settle(&mut self, game_id: i64, trigger: Trigger, done: bool) {
+        if self.game_id != game_id {
+            return;
+        }
+
+        match trigger {
+            Trigger::SessionStart => self.started = done,
+            Trigger::IntendedPick(cid) => {
+                if self.pick_pending == Some(cid) {
+                    self.pick_pending = None;
+                }
+                if done {
+                    self.pick = Some(cid);
+                }
+            }
+            Trigger::RunePage => self.rune_page = done,
+        }
+    }
+}
+
+pub struct ChampSelectChatSubscriber {
+    api: Arc<dyn LcuApiPort>,
+    chat: Arc<dyn ChatPort>,
+    resolver: Arc<dyn ChampionResolverPort>,
+    roles: Arc<RoleResolver>,
+    templates: Arc<TemplateStore>,
+    limit: Arc<ChatRateLimit>,
+    announced: Arc<Mutex<Announced>>,
+}
+
+impl ChampSelectChatSubscriber {
+    pub fn new(
+        api: Arc<dyn LcuApiPort>,
+        chat: Arc<dyn ChatPort>,
+        resolver: Arc<dyn ChampionResolverPort>,
+        roles: Arc<RoleResolver>,
+        templates: Arc<TemplateStore>,
+        limit: Arc<ChatRateLimit>,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            api,
+            chat,
+            resolver,
+            roles,
+            templates,
+            limit,
+            announced: Arc::new(Mutex::new(Announced::default())),
+        })
+    }
+
+    /// Triggers that fire for this session update, each at most once per change.
+    fn triggers(&self, session: &ChampSelectSessionPayload) -> Vec<Trigger> {
+        let mut a = self.announced.lock().unwrap();
+        if a.game_id != session.gameId {
+            *a = Announced {
+                game_id: session.gameId,
+                ..Announced::default()
+            };
+        }
+
+        let mut out = Vec::new();
+
+        if !a.started {
+            a.started = true;
+            out.push(Trigger::SessionStart);
+        }
+
+        let hover = session
+            .actions
+            .iter()
+            .flatten()
+            .find(|x| {
+                x.action_type == "pick"
+                    && x.actorCellId == session.localPlayerCellId
+                    && !x.completed
+                    && x.championId != 0
+            })
+            .map(|x| x.championId);
+
+        if let Some(cid) = hover.filter(|h| a.pick != Some(*h) && a.pick_pending != Some(*h)) {
+            a.pick_pending = Some(cid);
+            out.push(Trigger::IntendedPick(cid));
+        }
+
+        if session.timer.phase == "FINALIZATION" && !a.rune_page {
+            a.rune_page = true;
+            out.push(Trigger::RunePage);
+        }
+
+        out
+    }
+}
+
+async fn name_of(resolver: &dyn ChampionResolverPort, cid: Option<i32>) -> String {
+    match cid {
+        Some(cid) => resolver.resolve_name(cid).await.unwrap_or_default(),
+        None => String::new(),
+    }
+}
+
+impl LeagueEventSubscriber for ChampSelectChatSubscriber {
+    fn on_event(&self, event: &LeagueEvent) {
+        if event.event_type != EventType::ChampSelectSession {
+            return;
+        }
+
+        let source = event.data.get("payload").unwrap_or(&event.data);
+
+        let Ok(session) = serde_json::from_value::<ChampSelectSessionPayload>(source.clone())
+        else {
+            return;
+        };
+
+        let triggers = self.triggers(&session);
+        if triggers.is_empty() {
+            return;
+        }
+
+        let templates = self.templates.get();
+        let api = self.api.clone();
+        let chat = self.chat.clone();
+        let resolver = self.resolver.clone();
+        let limit = self.limit.clone();
+        let announced = self.announced.clone();
+        let role = self.roles.resolve(&session);
+
+        task::spawn(async move {
+            let req = BanPickRequest::from_session(&session, role.as_deref().unwrap_or(""));
+
+            for trigger in triggers {
+                let done = 'send: {
+                    let template = match trigger {
+                        Trigger::SessionStart => &templates.session_start,
+                        Trigger::IntendedPick(_) => &templates.intended_pick,
+                        Trigger::RunePage => &templates.rune_page,
+                    };
+
+                    let Some(template) = template.as_deref().filter(|t| !t.trim().is_empty())
+                    else {
+                        break 'send true;
+                    };
+
+                    let mut values = HashMap::new();
+                    values.insert("role", req.role.clone());
+                    values.insert("queue", req.queue_id.to_string());
+                    values.insert("champion", name_of(resolver.as_ref(), req.my_pick).await);
+                    values.insert(
+                        "opponent",
+                        name_of(resolver.as_ref(), req.lane_opponent()).await,
+                    );
+
+                    if let Trigger::RunePage = trigger {
+                        let left = session.timer.adjustedTimeLeftInPhase.unwrap_or(0);
+                        sleep(Duration::from_millis(
+                            (left - RUNE_PAGE_LEAD_MS).max(0) as u64
+                        ))
+                        .await;
+
+                        let page = api.get("/lol-perks/v1/currentpage").await.ok();
+                        let name = page
+                            .as_ref()
+                            .and_then(|p| p.get("name")?.as_str())
+                            .unwrap_or_default();
+                        values.insert("runePage", name.to_string());
+                    }
+
+                    // The role may not be known yet on the first updates; wait for it.
+                    if req.role.is_empty() && template.contains("{role}") {
+                        info!("[Chat] Role unknown, holding {:?} message", trigger);
+                        break 'send false;
+                    }
+
+                    let body = render(template, &values);
+                    if body.trim().is_empty() {
+                        break 'send true;
+                    }
+
+                    let conversation = match chat.champ_select_conversation().await {
+                        Ok(Some(id)) => id,
+                        Ok(None) => {
+                            info!("[Chat] No champ select conversation");
+                            break 'send false;
+                        }
+                        Err(e) => {
+                            info!("[Chat] Failed to find champ select conversation: {}", e);
+                            break 'send false;
+                        }
+                    };
+
+                    if let Err(reason) = limit.try_acquire(session.gameId) {
+                        info!("[Chat] Not sending {:?} message ({})", trigger, reason);
+                        break 'send false;
+                    }
+
+                    info!("[Chat] {:?}: {}", trigger, body);
+
+                    match chat.send(&conversation, &body).await {
+                        Ok(_) => true,
+                        Err(e) => {
+                            info!("[Chat] Failed to send message: {}", e);
+                            false
+                        }
+                    }
+                };
+
+                announced
+                    .lock()
+                    .unwrap()
+                    .settle(session.gameId, trigger, done);
+            }
+        });
+    }
+}
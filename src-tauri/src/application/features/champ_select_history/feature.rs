@@ -0,0 +1,50 @@
+use crate::application::features::champ_select_history::subscriber::ChampSelectHistorySubscriber;
+use crate::domain::champ_select::decision_reporter::DecisionReporter;
+use crate::domain::champ_select::session_control::SessionControl;
+use crate::domain::feature::{Feature, FeatureId};
+use crate::domain::ports::{
+    ChampSelectHistoryPort, LcuApiPort, LeagueEventPublisherPort, LeagueEventSubscriber,
+};
+use crate::domain::role_resolver::RoleResolver;
+use std::sync::Arc;
+
+pub struct ChampSelectHistoryFeature {
+    league_pub: Arc<dyn LeagueEventPublisherPort>,
+    subscriber: Arc<dyn LeagueEventSubscriber>,
+}
+
+impl ChampSelectHistoryFeature {
+    pub fn new(
+        league_pub: Arc<dyn LeagueEventPublisherPort>,
+        api: Arc<dyn LcuApiPort>,
+        history: Arc<dyn ChampSelectHistoryPort>,
+        roles: Arc<RoleResolver>,
+        control: Arc<SessionControl>,
+        decisions: Arc<DecisionReporter>,
+    ) -> Self {
+        let subscriber = ChampSelectHistorySubscriber::new(api, history, roles, control, decisions);
+
+        Self {
+            league_pub,
+            subscriber,
+        }
+    }
+}
+
+impl Feature for ChampSelectHistoryFeature {
+    fn id(&self) -> FeatureId {
+        FeatureId::ChampSelectHistory
+    }
+
+    fn display_name(&self) -> String {
+        "Champ Select History".into()
+    }
+
+    fn enable(&self) {
+        self.league_pub.subscribe(self.subscriber.clone());
+    }
+
+    fn disable(&self) {
+        self.league_pub.unsubscribe(&self.subscriber);
+    }
+}
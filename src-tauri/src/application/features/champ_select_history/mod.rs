@@ -0,0 +1,2 @@
+pub mod feature;
+pub mod subscriber;
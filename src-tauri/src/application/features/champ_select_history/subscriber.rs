@@ -0,0 +1,134 @@
+use chrono::{DateTime, Utc};
+use log::{info, warn};
+use serde_json::Value;
+use std::sync::{Arc, Mutex};
+use tokio::task;
+
+use crate::domain::champ_select::decision_reporter::DecisionReporter;
+use crate::domain::champ_select::history::{ChampSelectOutcome, ChampSelectRecord};
+use crate::domain::champ_select::models::ChampSelectSessionPayload;
+use crate::domain::champ_select::session_control::SessionControl;
+use crate::domain::events::{EventType, LeagueEvent};
+use crate::domain::ports::{ChampSelectHistoryPort, LcuApiPort, LeagueEventSubscriber};
+use crate::domain::role_resolver::RoleResolver;
+
+/// The champ select in progress, as last seen.
+struct Tracked {
+    game_id: i64,
+    started_at: DateTime<Utc>,
+    session: Value,
+    role: Option<String>,
+}
+
+pub struct ChampSelectHistorySubscriber {
+    api: Arc<dyn LcuApiPort>,
+    history: Arc<dyn ChampSelectHistoryPort>,
+    roles: Arc<RoleResolver>,
+    control: Arc<SessionControl>,
+    decisions: Arc<DecisionReporter>,
+    tracked: Arc<Mutex<Option<Tracked>>>,
+}
+
+impl ChampSelectHistorySubscriber {
+    pub fn new(
+        api: Arc<dyn LcuApiPort>,
+        history: Arc<dyn ChampSelectHistoryPort>,
+        roles: Arc<RoleResolver>,
+        control: Arc<SessionControl>,
+        decisions: Arc<DecisionReporter>,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            api,
+            history,
+            roles,
+            control,
+            decisions,
+            tracked: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    fn on_session(&self, raw: &Value) {
+        let Ok(session) = serde_json::from_value::<ChampSelectSessionPayload>(raw.clone()) else {
+            return;
+        };
+
+        let mut tracked = self.tracked.lock().unwrap();
+
+        let t = match tracked.as_mut() {
+            Some(t) if t.game_id == session.gameId => t,
+            _ => tracked.insert(Tracked {
+                game_id: session.gameId,
+                started_at: Utc::now(),
+                session: Value::Null,
+                role: None,
+            }),
+        };
+
+        t.session = raw.clone();
+        if let Some(role) = self.roles.resolve(&session) {
+            t.role = Some(role);
+        }
+    }
+
+    fn on_gameflow(&self, phase: &str) {
+        let outcome = match phase {
+            "ChampSelect" => return,
+            "Loading" | "GameStart" | "InProgress" | "Reconnect" => ChampSelectOutcome::Started,
+            _ => ChampSelectOutcome::Dodged,
+        };
+
+        let Some(t) = self.tracked.lock().unwrap().take() else {
+            return;
+        };
+
+        let Ok(session) = serde_json::from_value::<ChampSelectSessionPayload>(t.session) else {
+            return;
+        };
+
+        let game_id = t.game_id;
+        let mut record =
+            ChampSelectRecord::from_session(&session, t.started_at, outcome, |a, c| {
+                self.control.last_set_is(game_id, a, c)
+            });
+        record.role = t.role;
+        record.decisions = self.decisions.for_game(game_id);
+
+        info!(
+            "[History] Champ select {} ended ({:?}), recording",
+            game_id, outcome
+        );
+
+        let api = self.api.clone();
+        let history = self.history.clone();
+        task::spawn(async move {
+            // Read now rather than during finalization: the rune picker and
+            // the user may both still change the page until champ select ends.
+            record.rune_page = api
+                .get("/lol-perks/v1/currentpage")
+                .await
+                .ok()
+                .and_then(|p| Some(p.get("name")?.as_str()?.to_string()));
+
+            let appended = task::spawn_blocking(move || history.append(&record)).await;
+            if let Ok(Err(e)) = appended {
+                warn!("[History] Failed to record champ select: {}", e);
+            }
+        });
+    }
+}
+
+impl LeagueEventSubscriber for ChampSelectHistorySubscriber {
+    fn on_event(&self, event: &LeagueEvent) {
+        let source = event.data.get("payload").unwrap_or(&event.data);
+
+        match event.event_type {
+            EventType::ChampSelectSession => self.on_session(source),
+            EventType::GameflowSession => {
+                if let Some(phase) = source.get("phase").and_then(Value::as_str) {
+                    self.on_gameflow(phase);
+                }
+            }
+            _ => {}
+        }
+    }
+}
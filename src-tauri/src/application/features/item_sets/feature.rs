@@ -0,0 +1,78 @@
+use crate::adapters::outbound::lcu_item_set_writer::LcuItemSetWriter;
+use crate::adapters::outbound::league_config_item_set_writer::LeagueConfigItemSetWriter;
+use crate::application::features::item_sets::subscriber::{ItemSetSubscriber, ItemSetTarget};
+use crate::domain::feature::{Feature, FeatureId};
+use crate::domain::item_set_manager::ItemSetManager;
+use crate::domain::ports::{
+    ChampionResolverPort, LcuApiPort, LeagueEventPublisherPort, LeagueEventSubscriber,
+    LockfilePort,
+};
+use serde_json::Value;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+pub struct ItemSetFeature {
+    league_pub: Arc<dyn LeagueEventPublisherPort>,
+    subscriber: Arc<dyn LeagueEventSubscriber>,
+    sets_subscriber: Arc<ItemSetSubscriber>,
+    file_writer: Arc<LeagueConfigItemSetWriter>,
+}
+
+impl ItemSetFeature {
+    pub fn new(
+        league_pub: Arc<dyn LeagueEventPublisherPort>,
+        api: Arc<dyn LcuApiPort>,
+        lockfile: Arc<dyn LockfilePort>,
+        sets: Arc<ItemSetManager>,
+        resolver: Arc<dyn ChampionResolverPort>,
+    ) -> Self {
+        let file_writer = Arc::new(LeagueConfigItemSetWriter::new(lockfile));
+        let client_writer = Arc::new(LcuItemSetWriter::new(api.clone()));
+
+        let sets_subscriber =
+            ItemSetSubscriber::new(api, sets, resolver, file_writer.clone(), client_writer);
+
+        Self {
+            league_pub,
+            subscriber: sets_subscriber.clone(),
+            sets_subscriber,
+            file_writer,
+        }
+    }
+}
+
+impl Feature for ItemSetFeature {
+    fn id(&self) -> FeatureId {
+        FeatureId::ItemSets
+    }
+
+    fn display_name(&self) -> String {
+        "Item Set Writer".into()
+    }
+
+    fn enable(&self) {
+        self.league_pub.subscribe(self.subscriber.clone());
+    }
+
+    fn disable(&self) {
+        self.league_pub.unsubscribe(&self.subscriber);
+    }
+
+    fn configure(&self, settings: Value) {
+        if let Some(t) = settings.get("target").and_then(|v| v.as_str()) {
+            let target = match t {
+                "client" => ItemSetTarget::Client,
+                _ => ItemSetTarget::Files,
+            };
+            self.sets_subscriber.set_target(target);
+        }
+
+        if let Some(p) = settings.get("installPath") {
+            let dir = p
+                .as_str()
+                .filter(|s| !s.is_empty())
+                .map(PathBuf::from);
+            self.file_writer.set_install_dir(dir);
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use log::info;
+use std::sync::{Arc, Mutex, RwLock};
+use tokio::task;
+
+use crate::domain::champ_select::models::ChampSelectSessionPayload;
+use crate::domain::events::{EventType, LeagueEvent};
+use crate::domain::item_set_manager::ItemSetManager;
+use crate::domain::ports::{
+    ChampionResolverPort, ItemSetWriterPort, LcuApiPort, LeagueEventSubscriber,
+};
+
+/// Summoner's Rift, used when the gameflow session has no map yet.
+const DEFAULT_MAP_ID: i32 = 11;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemSetTarget {
+    /// `Config/Champions/<Key>/Recommended` files in the League install.
+    Files,
+    /// The client's `/lol-item-sets` endpoint.
+    Client,
+}
+
+pub struct ItemSetSubscriber {
+    api: Arc<dyn LcuApiPort>,
+    sets: Arc<ItemSetManager>,
+    resolver: Arc<dyn ChampionResolverPort>,
+    file_writer: Arc<dyn ItemSetWriterPort>,
+    client_writer: Arc<dyn ItemSetWriterPort>,
+    target: RwLock<ItemSetTarget>,
+    /// (gameId, championId) we already wrote sets for.
+    handled: Arc<Mutex<Option<(i64, i64)>>>,
+}
+
+impl ItemSetSubscriber {
+    pub fn new(
+        api: Arc<dyn LcuApiPort>,
+        sets: Arc<ItemSetManager>,
+        resolver: Arc<dyn ChampionResolverPort>,
+        file_writer: Arc<dyn ItemSetWriterPort>,
+        client_writer: Arc<dyn ItemSetWriterPort>,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            api,
+            sets,
+            resolver,
+            file_writer,
+            client_writer,
+            target: RwLock::new(ItemSetTarget::Files),
+            handled: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    pub fn set_target(&self, target: ItemSetTarget) {
+        *self.target.write().unwrap() = target;
+    }
+}
+
+impl LeagueEventSubscriber for ItemSetSubscriber {
+    fn on_event(&self, event: &LeagueEvent) {
+        if event.event_type != EventType::ChampSelectSession {
+            return;
+        }
+
+        let source = event.data.get("payload").unwrap_or(&event.data);
+
+        let Ok(session) = serde_json::from_value::<ChampSelectSessionPayload>(source.clone())
+        else {
+            info!("[ItemSets] Failed to parse ChampSelectSession");
+            return;
+        };
+
+        let Some(champion_id) = session.locked_champion() else {
+            return;
+        };
+
+        let key = (session.gameId, champion_id);
+        {
+            let mut handled = self.handled.lock().unwrap();
+            if *handled == Some(key) {
+                return;
+            }
+            *handled = Some(key);
+        }
+
+        let api = self.api.clone();
+        let sets = self.sets.clone();
+        let resolver = self.resolver.clone();
+        let writer = match *self.target.read().unwrap() {
+            ItemSetTarget::Files => self.file_writer.clone(),
+            ItemSetTarget::Client => self.client_writer.clone(),
+        };
+
+        task::spawn(async move {
+            let champion_id = champion_id as i32;
+
+            let (Some(name), Some(champion_key)) = (
+                resolver.resolve_name(champion_id).await,
+                resolver.resolve_key(champion_id).await,
+            ) else {
+                info!("[ItemSets] Unknown champion {}", champion_id);
+                return;
+            };
+
+            let map_id = current_map_id(api.as_ref()).await;
+            let matching = sets.find_sets(&name, map_id);
+
+            info!(
+                "[ItemSets] Writing {} set(s) for {} on map {}",
+                matching.len(),
+                name,
+                map_id
+            );
+
+            if let Err(e) = writer.write(champion_id, &champion_key, &matching).await {
+                info!("[ItemSets] Failed to write item sets for {}: {:?}", name, e);
+            }
+        });
+    }
+}
+
+async fn current_map_id(api: &dyn LcuApiPort) -> i32 {
+    api.get("/lol-gameflow/v1/session")
+        .await
+        .ok()
+        .and_then(|s| s.pointer("/gameData/queue/mapId").and_then(|v| v.as_i64()))
+        .map(|id| id as i32)
+        .unwrap_or(DEFAULT_MAP_ID)
+}
@@ -1,7 +1,14 @@
 pub(crate) mod ingame_log;
+pub(crate) mod item_sets;
 pub(crate) mod league_log;
 pub(crate) mod match_ready;
 
 pub(crate) mod auto_pick_ban;
+pub(crate) mod bench_swap;
+pub(crate) mod champ_select_chat;
+pub(crate) mod champ_select_history;
 
 pub(crate) mod rune_picker;
+
+pub(crate) mod skin_picker;
+pub(crate) mod trade_responder;
@@ -1,6 +1,7 @@
 use crate::application::features::rune_picker::subscriber::RunePickerSubscriber;
 use crate::domain::delay_state::DelayState;
 use crate::domain::feature::{Feature, FeatureId};
+use crate::domain::role_resolver::RoleResolver;
 use crate::domain::ports::{
     ChampionResolverPort, LcuApiPort, LeagueEventPublisherPort, LeagueEventSubscriber,
 };
@@ -11,6 +12,7 @@ pub struct RunePickerFeature {
     league_pub: Arc<dyn LeagueEventPublisherPort>,
     subscriber: Arc<dyn LeagueEventSubscriber>,
     delay: Arc<DelayState>,
+    pages: Arc<RunePageManager>,
 }
 
 impl RunePickerFeature {
@@ -20,12 +22,15 @@ impl RunePickerFeature {
         delay: Arc<DelayState>,
         pages: Arc<RunePageManager>,
         resolver: Arc<dyn ChampionResolverPort>,
+        roles: Arc<RoleResolver>,
     ) -> Self {
-        let subscriber = RunePickerSubscriber::new(api, delay.clone(), pages, resolver);
+        let subscriber =
+            RunePickerSubscriber::new(api, delay.clone(), pages.clone(), resolver, roles);
         Self {
             league_pub,
             subscriber,
             delay,
+            pages,
         }
     }
 }
@@ -51,5 +56,10 @@ impl Feature for RunePickerFeature {
         if let Some(v) = settings.get("delayMs").and_then(|v| v.as_u64()) {
             self.delay.set(v);
         }
+
+        // Without this, Overseer never deletes a page it did not create.
+        if let Some(v) = settings.get("replaceOwnPages").and_then(|v| v.as_bool()) {
+            self.pages.set_replace_own_pages(v);
+        }
     }
 }
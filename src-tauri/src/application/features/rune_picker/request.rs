@@ -1,16 +1,64 @@
+use crate::application::features::auto_pick_ban::ban_pick_request::BanPickRequest;
+use crate::domain::champ_select::models::ChampSelectSessionPayload;
 use crate::domain::ports::ChampionResolverPort;
+use crate::domain::role_resolver::RoleResolver;
+use crate::domain::rune_page::{ContextChampion, RunePageContext};
 use serde_json::Value;
 
 pub struct RunePickRequest {
     pub champion_id: i32,
-    pub champion_name: String,
+    pub champion: ContextChampion,
     pub role: String,
+    pub context: RunePageContext,
+}
+
+async fn context_champion(
+    resolver: &dyn ChampionResolverPort,
+    cid: i32,
+) -> Option<ContextChampion> {
+    Some(ContextChampion {
+        name: resolver.resolve_name(cid).await?,
+        tags: resolver.resolve_tags(cid).await,
+    })
+}
+
+/// Queue, lane opponent (once revealed) and allies for page conditions.
+async fn build_context(
+    session: &Value,
+    role: &str,
+    resolver: &dyn ChampionResolverPort,
+) -> RunePageContext {
+    let Ok(session) = serde_json::from_value::<ChampSelectSessionPayload>(session.clone()) else {
+        return RunePageContext::default();
+    };
+
+    let lane_opponent = match BanPickRequest::from_session(&session, role).lane_opponent() {
+        Some(cid) => context_champion(resolver, cid).await,
+        None => None,
+    };
+
+    let mut allies = Vec::new();
+    for p in &session.myTeam {
+        if p.cellId == session.localPlayerCellId || p.championId == 0 {
+            continue;
+        }
+        if let Some(c) = context_champion(resolver, p.championId as i32).await {
+            allies.push(c);
+        }
+    }
+
+    RunePageContext {
+        queue_id: session.queueId,
+        lane_opponent,
+        allies,
+    }
 }
 
 impl RunePickRequest {
     pub async fn from_session(
         session: &Value,
         resolver: &dyn ChampionResolverPort,
+        roles: &RoleResolver,
     ) -> Option<Self> {
         let cell = session.get("localPlayerCellId")?.as_i64()? as i32;
         let team = session.get("myTeam")?.as_array()?;
@@ -27,28 +75,22 @@ impl RunePickRequest {
             return None;
         }
 
-        let queue = session.get("queueId")?.as_i64()? as i32;
-
-        let role = player
-            .get("assignedPosition")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_lowercase();
+        let queue = session.get("queueId")?.as_i64()?;
+        let game = session.get("gameId").and_then(|v| v.as_i64()).unwrap_or(0);
+        let assigned = player.get("assignedPosition").and_then(|v| v.as_str());
 
-        let role = match role.as_str() {
-            "utility" => "support".into(),
-            "middle" => "mid".into(),
-            "" if queue == 3140 => "support".into(),
-            "" => "unknown".into(),
-            x => x.into(),
-        };
+        let role = roles
+            .resolve_for(game, queue, assigned)
+            .unwrap_or_else(|| "unknown".into());
 
-        let name = resolver.resolve_name(champ).await?;
+        let champion = context_champion(resolver, champ).await?;
+        let context = build_context(session, &role, resolver).await;
 
         Some(Self {
             champion_id: champ,
-            champion_name: name,
+            champion,
             role,
+            context,
         })
     }
 }
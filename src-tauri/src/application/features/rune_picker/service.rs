@@ -14,19 +14,20 @@ impl RunePickerService {
     }
 
     pub async fn apply(&self, req: RunePickRequest) {
-        let name = req.champion_name;
         let champ_id = req.champion_id;
         let role = req.role;
 
         log::info!(
-            "RunePickerService: Applying runes for {} ({}) [{}]",
-            name,
+            "RunePickerService: Applying runes for {} ({}) [{}], lane opponent {:?}",
+            req.champion.name,
             role,
-            champ_id
+            champ_id,
+            req.context.lane_opponent.as_ref().map(|c| &c.name)
         );
 
+        // Runs on every session update, so a matchup page takes over once the opponent shows.
         self.pages
-            .apply_for_champion(&name, champ_id, &role, self.api.as_ref())
+            .apply_for_champion(&req.champion, &role, &req.context, self.api.as_ref())
             .await;
     }
 }
@@ -3,6 +3,7 @@ use crate::application::features::rune_picker::service::RunePickerService;
 use crate::domain::delay_state::DelayState;
 use crate::domain::events::{EventType, LeagueEvent};
 use crate::domain::ports::{ChampionResolverPort, LcuApiPort, LeagueEventSubscriber};
+use crate::domain::role_resolver::RoleResolver;
 use crate::domain::rune_page_manager::RunePageManager;
 use log::info;
 use std::sync::Arc;
@@ -14,6 +15,7 @@ pub struct RunePickerSubscriber {
     delay: Arc<DelayState>,
     pages: Arc<RunePageManager>,
     resolver: Arc<dyn ChampionResolverPort>,
+    roles: Arc<RoleResolver>,
 }
 
 impl RunePickerSubscriber {
@@ -22,12 +24,14 @@ impl RunePickerSubscriber {
         delay: Arc<DelayState>,
         pages: Arc<RunePageManager>,
         resolver: Arc<dyn ChampionResolverPort>,
+        roles: Arc<RoleResolver>,
     ) -> Arc<Self> {
         Arc::new(Self {
             api,
             delay,
             pages,
             resolver,
+            roles,
         })
     }
 }
@@ -55,13 +59,14 @@ impl LeagueEventSubscriber for RunePickerSubscriber {
         let api = self.api.clone();
         let pages = self.pages.clone();
         let resolver = self.resolver.clone();
+        let roles = self.roles.clone();
 
         info!("RunePicker: scheduling rune evaluation in {} ms", delay);
 
         task::spawn(async move {
             sleep(Duration::from_millis(delay)).await;
 
-            let req = RunePickRequest::from_session(&session, resolver.as_ref()).await;
+            let req = RunePickRequest::from_session(&session, resolver.as_ref(), &roles).await;
             if req.is_none() {
                 return;
             }
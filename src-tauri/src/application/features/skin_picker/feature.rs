@@ -0,0 +1,88 @@
+use crate::application::features::skin_picker::skin_preferences::{
+    SkinPreference, SkinPreferences,
+};
+use crate::application::features::skin_picker::subscriber::SkinPickerSubscriber;
+use crate::domain::feature::{Feature, FeatureId};
+use crate::domain::ports::{
+    ChampionResolverPort, LcuApiPort, LeagueEventPublisherPort, LeagueEventSubscriber,
+    SkinCatalogPort,
+};
+use log::info;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub struct SkinPickerFeature {
+    league_pub: Arc<dyn LeagueEventPublisherPort>,
+    subscriber: Arc<dyn LeagueEventSubscriber>,
+
+    prefs: Arc<SkinPreferences>,
+    resolver: Arc<dyn ChampionResolverPort>,
+}
+
+impl SkinPickerFeature {
+    pub fn new(
+        league_pub: Arc<dyn LeagueEventPublisherPort>,
+        api: Arc<dyn LcuApiPort>,
+        skins: Arc<dyn SkinCatalogPort>,
+        resolver: Arc<dyn ChampionResolverPort>,
+    ) -> Self {
+        let prefs = Arc::new(SkinPreferences::new());
+        let subscriber = SkinPickerSubscriber::new(api, skins, prefs.clone());
+
+        Self {
+            league_pub,
+            subscriber,
+            prefs,
+            resolver,
+        }
+    }
+
+    fn apply_skin_settings(&self, val: &Value) {
+        let Some(obj) = val.as_object() else {
+            return;
+        };
+
+        let mut champ_map = HashMap::<String, SkinPreference>::new();
+
+        for (champion, pref) in obj {
+            match serde_json::from_value::<SkinPreference>(pref.clone()) {
+                Ok(p) => {
+                    champ_map.insert(champion.clone(), p);
+                }
+                Err(e) => info!("⚠ Invalid skin preference for {}: {}", champion, e),
+            }
+        }
+
+        let prefs = self.prefs.clone();
+        let resolver = self.resolver.clone();
+
+        tauri::async_runtime::spawn(async move {
+            prefs.resolve_from_names(champ_map, resolver).await;
+        });
+    }
+}
+
+impl Feature for SkinPickerFeature {
+    fn id(&self) -> FeatureId {
+        FeatureId::SkinPicker
+    }
+
+    fn display_name(&self) -> String {
+        "Skin Picker".into()
+    }
+
+    fn enable(&self) {
+        self.league_pub.subscribe(self.subscriber.clone());
+    }
+
+    fn disable(&self) {
+        self.league_pub.unsubscribe(&self.subscriber);
+    }
+
+    fn configure(&self, settings: Value) {
+        if let Some(s) = settings.get("skinPreferences") {
+            self.apply_skin_settings(s);
+        }
+    }
+}
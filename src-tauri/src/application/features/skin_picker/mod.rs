@@ -0,0 +1,3 @@
+pub mod feature;
+pub mod skin_preferences;
+pub mod subscriber;
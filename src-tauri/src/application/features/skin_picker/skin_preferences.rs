@@ -0,0 +1,55 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::ports::ChampionResolverPort;
+use log::info;
+
+/// How a skin is chosen for a champion once it is locked in.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "mode", rename_all = "camelCase")]
+pub enum SkinPreference {
+    Favourite {
+        #[serde(rename = "skinId")]
+        skin_id: i32,
+    },
+    Random,
+    RandomWithChromas,
+}
+
+pub struct SkinPreferences {
+    data: RwLock<HashMap<i32, SkinPreference>>,
+}
+
+impl SkinPreferences {
+    pub fn new() -> Self {
+        Self {
+            data: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub async fn get_for_champion(&self, champion_id: i32) -> Option<SkinPreference> {
+        let g = self.data.read().await;
+        g.get(&champion_id).cloned()
+    }
+
+    pub async fn resolve_from_names(
+        &self,
+        champ_map: HashMap<String, SkinPreference>,
+        resolver: Arc<dyn ChampionResolverPort>,
+    ) {
+        let mut resolved: HashMap<i32, SkinPreference> = HashMap::new();
+
+        for (name, pref) in champ_map {
+            if let Some(id) = resolver.resolve_id(&name).await {
+                resolved.insert(id, pref);
+            } else {
+                info!("⚠ Unknown champion in skin preferences: {}", name);
+            }
+        }
+
+        let mut g = self.data.write().await;
+        *g = resolved;
+    }
+}
@@ -0,0 +1,135 @@
+use log::info;
+use rand::seq::SliceRandom;
+use std::sync::{Arc, Mutex};
+use tokio::task;
+
+use crate::application::features::skin_picker::skin_preferences::{
+    SkinPreference, SkinPreferences,
+};
+use crate::domain::champ_select::models::ChampSelectSessionPayload;
+use crate::domain::events::{EventType, LeagueEvent};
+use crate::domain::ports::{LcuApiPort, LeagueEventSubscriber, SkinCatalogPort};
+use crate::domain::skin::Skin;
+
+pub struct SkinPickerSubscriber {
+    api: Arc<dyn LcuApiPort>,
+    skins: Arc<dyn SkinCatalogPort>,
+    prefs: Arc<SkinPreferences>,
+    /// (gameId, championId) we already selected a skin for.
+    handled: Arc<Mutex<Option<(i64, i64)>>>,
+}
+
+impl SkinPickerSubscriber {
+    pub fn new(
+        api: Arc<dyn LcuApiPort>,
+        skins: Arc<dyn SkinCatalogPort>,
+        prefs: Arc<SkinPreferences>,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            api,
+            skins,
+            prefs,
+            handled: Arc::new(Mutex::new(None)),
+        })
+    }
+}
+
+impl LeagueEventSubscriber for SkinPickerSubscriber {
+    fn on_event(&self, event: &LeagueEvent) {
+        if event.event_type != EventType::ChampSelectSession {
+            return;
+        }
+
+        let source = event.data.get("payload").unwrap_or(&event.data);
+
+        let Ok(session) = serde_json::from_value::<ChampSelectSessionPayload>(source.clone())
+        else {
+            info!("[SkinPicker] Failed to parse ChampSelectSession");
+            return;
+        };
+
+        let Some(champion_id) = session.locked_champion() else {
+            return;
+        };
+
+        let key = (session.gameId, champion_id);
+        {
+            let mut handled = self.handled.lock().unwrap();
+            if *handled == Some(key) {
+                return;
+            }
+            *handled = Some(key);
+        }
+
+        let api = self.api.clone();
+        let skins = self.skins.clone();
+        let prefs = self.prefs.clone();
+        let handled = self.handled.clone();
+
+        task::spawn(async move {
+            let Some(pref) = prefs.get_for_champion(champion_id as i32).await else {
+                info!("[SkinPicker] No skin preference for champion {}", champion_id);
+                return;
+            };
+
+            let carousel = match skins.carousel_skins().await {
+                Ok(v) => v,
+                Err(e) => {
+                    info!("[SkinPicker] Failed to fetch skin carousel: {:?}", e);
+                    *handled.lock().unwrap() = None;
+                    return;
+                }
+            };
+
+            let owned: Vec<Skin> = carousel
+                .into_iter()
+                .filter(|s| s.champion_id as i64 == champion_id)
+                .collect();
+
+            let Some(skin_id) = choose_skin(&pref, &owned) else {
+                info!(
+                    "[SkinPicker] No selectable skin for champion {} ({:?})",
+                    champion_id, pref
+                );
+                return;
+            };
+
+            info!("[SkinPicker] Selecting skin {} for champion {}", skin_id, champion_id);
+
+            let payload = serde_json::json!({
+                "selectedSkinId": skin_id
+            });
+
+            if let Err(e) = api
+                .patch("/lol-champ-select/v1/session/my-selection", Some(&payload))
+                .await
+            {
+                info!("[SkinPicker] Failed to select skin {}: {:?}", skin_id, e);
+            }
+        });
+    }
+}
+
+fn choose_skin(pref: &SkinPreference, owned: &[Skin]) -> Option<i32> {
+    let mut rng = rand::thread_rng();
+
+    match pref {
+        SkinPreference::Favourite { skin_id } => owned
+            .iter()
+            .flat_map(|s| std::iter::once(s.id).chain(s.chromas.iter().map(|c| c.id)))
+            .find(|id| id == skin_id),
+
+        SkinPreference::Random => {
+            let pool: Vec<i32> = owned.iter().map(|s| s.id).collect();
+            pool.choose(&mut rng).copied()
+        }
+
+        SkinPreference::RandomWithChromas => {
+            let pool: Vec<i32> = owned
+                .iter()
+                .flat_map(|s| std::iter::once(s.id).chain(s.chromas.iter().map(|c| c.id)))
+                .collect();
+            pool.choose(&mut rng).copied()
+        }
+    }
+}
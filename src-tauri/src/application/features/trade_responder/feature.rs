@@ -0,0 +1,85 @@
+use crate::application::features::auto_pick_ban::champion_preferences::ChampionPreferences;
+use crate::application::features::trade_responder::subscriber::TradeResponderSubscriber;
+use crate::application::features::trade_responder::trade_rules::TradeRules;
+use crate::domain::feature::{Feature, FeatureId};
+use crate::domain::role_resolver::RoleResolver;
+use crate::domain::ports::{
+    ChampionResolverPort, LcuApiPort, LeagueEventPublisherPort, LeagueEventSubscriber,
+    LogPublisherPort,
+};
+use serde_json::Value;
+use std::sync::Arc;
+
+pub struct TradeResponderFeature {
+    league_pub: Arc<dyn LeagueEventPublisherPort>,
+    subscriber: Arc<dyn LeagueEventSubscriber>,
+
+    rules: Arc<TradeRules>,
+    resolver: Arc<dyn ChampionResolverPort>,
+}
+
+impl TradeResponderFeature {
+    pub fn new(
+        league_pub: Arc<dyn LeagueEventPublisherPort>,
+        api: Arc<dyn LcuApiPort>,
+        log_pub: Arc<dyn LogPublisherPort>,
+        prefs: Arc<ChampionPreferences>,
+        resolver: Arc<dyn ChampionResolverPort>,
+        roles: Arc<RoleResolver>,
+    ) -> Self {
+        let rules = Arc::new(TradeRules::new());
+        let subscriber =
+            TradeResponderSubscriber::new(api, log_pub, prefs, rules.clone(), roles);
+
+        Self {
+            league_pub,
+            subscriber,
+            rules,
+            resolver,
+        }
+    }
+
+    fn string_list(val: Option<&Value>) -> Vec<String> {
+        val.and_then(|v| v.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str().map(|x| x.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl Feature for TradeResponderFeature {
+    fn id(&self) -> FeatureId {
+        FeatureId::TradeResponder
+    }
+
+    fn display_name(&self) -> String {
+        "Trade & Swap Responder".into()
+    }
+
+    fn enable(&self) {
+        self.league_pub.subscribe(self.subscriber.clone());
+    }
+
+    fn disable(&self) {
+        self.league_pub.unsubscribe(&self.subscriber);
+    }
+
+    fn configure(&self, settings: Value) {
+        let Some(b) = settings.get("blocklist") else {
+            return;
+        };
+
+        let champions = Self::string_list(b.get("champions"));
+        let players = Self::string_list(b.get("players"));
+
+        let rules = self.rules.clone();
+        let resolver = self.resolver.clone();
+
+        tauri::async_runtime::spawn(async move {
+            rules.resolve_blocklist(champions, players, resolver).await;
+        });
+    }
+}
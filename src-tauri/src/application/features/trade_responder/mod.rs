@@ -0,0 +1,3 @@
+pub mod feature;
+pub mod subscriber;
+pub mod trade_rules;
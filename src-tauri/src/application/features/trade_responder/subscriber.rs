@@ -0,0 +1,170 @@
+use log::info;
+use serde_json::json;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use tokio::task;
+
+use crate::application::features::auto_pick_ban::champion_preferences::ChampionPreferences;
+use crate::application::features::trade_responder::trade_rules::{TradeRules, Verdict};
+use crate::domain::champ_select::models::ChampSelectSessionPayload;
+use crate::domain::events::{EventType, LeagueEvent};
+use crate::domain::log_entry::LogEntry;
+use crate::domain::role_resolver::RoleResolver;
+use crate::domain::ports::{LcuApiPort, LeagueEventSubscriber, LogPublisherPort};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum RequestKind {
+    Trade,
+    PickOrderSwap,
+}
+
+impl RequestKind {
+    fn path(&self) -> &'static str {
+        match self {
+            RequestKind::Trade => "trades",
+            RequestKind::PickOrderSwap => "pick-order-swaps",
+        }
+    }
+}
+
+/// gameId and the requests already answered in it.
+type AnsweredRequests = (i64, HashSet<(RequestKind, i64)>);
+
+pub struct TradeResponderSubscriber {
+    api: Arc<dyn LcuApiPort>,
+    log_pub: Arc<dyn LogPublisherPort>,
+    prefs: Arc<ChampionPreferences>,
+    rules: Arc<TradeRules>,
+    roles: Arc<RoleResolver>,
+    answered: Mutex<AnsweredRequests>,
+}
+
+impl TradeResponderSubscriber {
+    pub fn new(
+        api: Arc<dyn LcuApiPort>,
+        log_pub: Arc<dyn LogPublisherPort>,
+        prefs: Arc<ChampionPreferences>,
+        rules: Arc<TradeRules>,
+        roles: Arc<RoleResolver>,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            api,
+            log_pub,
+            prefs,
+            rules,
+            roles,
+            answered: Mutex::new((0, HashSet::new())),
+        })
+    }
+
+    /// Marks a request as answered; false if it already was this session.
+    fn claim(&self, game_id: i64, kind: RequestKind, id: i64) -> bool {
+        let mut guard = self.answered.lock().unwrap();
+        if guard.0 != game_id {
+            *guard = (game_id, HashSet::new());
+        }
+        guard.1.insert((kind, id))
+    }
+}
+
+impl LeagueEventSubscriber for TradeResponderSubscriber {
+    fn on_event(&self, event: &LeagueEvent) {
+        if event.event_type != EventType::ChampSelectSession {
+            return;
+        }
+
+        let source = event.data.get("payload").unwrap_or(&event.data);
+
+        let Ok(session) = serde_json::from_value::<ChampSelectSessionPayload>(source.clone())
+        else {
+            info!("[TradeResponder] Failed to parse ChampSelectSession");
+            return;
+        };
+
+        let incoming: Vec<(RequestKind, i64, i64)> = session
+            .trades
+            .iter()
+            .map(|t| (RequestKind::Trade, t))
+            .chain(
+                session
+                    .pickOrderSwaps
+                    .iter()
+                    .map(|s| (RequestKind::PickOrderSwap, s)),
+            )
+            .filter(|(_, r)| r.state == "RECEIVED")
+            .filter(|(kind, r)| self.claim(session.gameId, *kind, r.id))
+            .map(|(kind, r)| (kind, r.id, r.cellId))
+            .collect();
+
+        if incoming.is_empty() {
+            return;
+        }
+
+        let api = self.api.clone();
+        let log_pub = self.log_pub.clone();
+        let prefs = self.prefs.clone();
+        let rules = self.rules.clone();
+        let roles = self.roles.clone();
+
+        task::spawn(async move {
+            let role_prefs = match roles.resolve(&session) {
+                Some(role) => prefs.get_for(session.queueId, &role).await,
+                None => Vec::new(),
+            };
+
+            for (kind, id, cell) in incoming {
+                let Some(requester) = session.myTeam.iter().find(|p| p.cellId == cell) else {
+                    continue;
+                };
+
+                let verdict = match kind {
+                    RequestKind::Trade => {
+                        rules.evaluate_trade(&session, requester, &role_prefs).await
+                    }
+                    RequestKind::PickOrderSwap => {
+                        rules.evaluate_pick_order_swap(&session, requester).await
+                    }
+                };
+
+                respond(api.as_ref(), log_pub.as_ref(), kind, id, &verdict).await;
+            }
+        });
+    }
+}
+
+async fn respond(
+    api: &dyn LcuApiPort,
+    log_pub: &dyn LogPublisherPort,
+    kind: RequestKind,
+    id: i64,
+    verdict: &Verdict,
+) {
+    let answer = if verdict.accept { "accept" } else { "decline" };
+    let path = format!(
+        "/lol-champ-select/v1/session/{}/{}/{}",
+        kind.path(),
+        id,
+        answer
+    );
+
+    info!(
+        "[TradeResponder] {} {:?} {}: {}",
+        answer, kind, id, verdict.reason
+    );
+
+    let result = api.post(&path, None).await;
+    if let Err(e) = &result {
+        info!("[TradeResponder] Failed to {} {:?} {}: {:?}", answer, kind, id, e);
+    }
+
+    log_pub.publish(LogEntry {
+        source: "trade".into(),
+        payload: json!({
+            "kind": format!("{:?}", kind),
+            "id": id,
+            "decision": answer,
+            "reason": verdict.reason,
+            "ok": result.is_ok(),
+        }),
+    });
+}
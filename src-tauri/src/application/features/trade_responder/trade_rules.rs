@@ -0,0 +1,178 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::champ_select::models::{ChampSelectSessionPayload, CsPlayer};
+use crate::domain::ports::ChampionResolverPort;
+use log::info;
+
+/// Outcome of evaluating a trade or swap request, with a human-readable reason.
+#[derive(Debug, Clone)]
+pub struct Verdict {
+    pub accept: bool,
+    pub reason: String,
+}
+
+impl Verdict {
+    fn accept(reason: String) -> Self {
+        Self {
+            accept: true,
+            reason,
+        }
+    }
+
+    fn decline(reason: String) -> Self {
+        Self {
+            accept: false,
+            reason,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Blocklist {
+    champions: HashSet<i32>,
+    /// Lowercase `gameName#tagLine`, `gameName` or puuid.
+    players: HashSet<String>,
+}
+
+pub struct TradeRules {
+    blocklist: RwLock<Blocklist>,
+}
+
+impl TradeRules {
+    pub fn new() -> Self {
+        Self {
+            blocklist: RwLock::new(Blocklist::default()),
+        }
+    }
+
+    pub async fn resolve_blocklist(
+        &self,
+        champion_names: Vec<String>,
+        players: Vec<String>,
+        resolver: Arc<dyn ChampionResolverPort>,
+    ) {
+        let mut champions = HashSet::new();
+
+        for name in champion_names {
+            if let Some(id) = resolver.resolve_id(&name).await {
+                champions.insert(id);
+            } else {
+                info!("⚠ Unknown champion in trade blocklist: {}", name);
+            }
+        }
+
+        let players = players.into_iter().map(|p| p.to_lowercase()).collect();
+
+        let mut g = self.blocklist.write().await;
+        *g = Blocklist { champions, players };
+    }
+
+    async fn blocked_player(&self, player: &CsPlayer) -> bool {
+        let g = self.blocklist.read().await;
+
+        let mut ids = Vec::new();
+        if let Some(puuid) = &player.puuid {
+            ids.push(puuid.to_lowercase());
+        }
+        if let Some(name) = &player.gameName {
+            ids.push(name.to_lowercase());
+            if let Some(tag) = &player.tagLine {
+                ids.push(format!("{}#{}", name, tag).to_lowercase());
+            }
+        }
+
+        ids.iter().any(|id| g.players.contains(id))
+    }
+
+    /// Accept a trade only if the offered champion ranks higher than ours for our role.
+    pub async fn evaluate_trade(
+        &self,
+        session: &ChampSelectSessionPayload,
+        requester: &CsPlayer,
+        role_prefs: &[i32],
+    ) -> Verdict {
+        if self.blocked_player(requester).await {
+            return Verdict::decline(format!("cell {} is blocklisted", requester.cellId));
+        }
+
+        let offered = requester.championId as i32;
+        let mine = session.local_player().map(|p| p.championId).unwrap_or(0) as i32;
+
+        if self.blocklist.read().await.champions.contains(&offered) {
+            return Verdict::decline(format!("offered champion {} is blocklisted", offered));
+        }
+
+        let rank = |cid: i32| role_prefs.iter().position(|p| *p == cid);
+
+        match (rank(offered), rank(mine)) {
+            (Some(o), Some(m)) if o < m => Verdict::accept(format!(
+                "offered {} ranks #{} above my {} (#{})",
+                offered,
+                o + 1,
+                mine,
+                m + 1
+            )),
+            (Some(o), None) => Verdict::accept(format!(
+                "offered {} ranks #{}, my {} is not in my pool",
+                offered,
+                o + 1,
+                mine
+            )),
+            (None, _) => Verdict::decline(format!("offered {} is not in my pool", offered)),
+            (Some(o), Some(m)) => Verdict::decline(format!(
+                "offered {} ranks #{}, not above my {} (#{})",
+                offered,
+                o + 1,
+                mine,
+                m + 1
+            )),
+        }
+    }
+
+    /// Accept a pick-order swap only if it moves us earlier in the pick order.
+    pub async fn evaluate_pick_order_swap(
+        &self,
+        session: &ChampSelectSessionPayload,
+        requester: &CsPlayer,
+    ) -> Verdict {
+        if self.blocked_player(requester).await {
+            return Verdict::decline(format!("cell {} is blocklisted", requester.cellId));
+        }
+
+        let order = pick_order(session);
+
+        match (
+            order.get(&requester.cellId),
+            order.get(&session.localPlayerCellId),
+        ) {
+            (Some(theirs), Some(mine)) if theirs < mine => Verdict::accept(format!(
+                "moves me from pick {} to pick {}",
+                mine + 1,
+                theirs + 1
+            )),
+            (Some(theirs), Some(mine)) => Verdict::decline(format!(
+                "would move me from pick {} to pick {}",
+                mine + 1,
+                theirs + 1
+            )),
+            _ => Verdict::decline("pick order unknown".into()),
+        }
+    }
+}
+
+/// Position of each ally cell in the pick order.
+fn pick_order(session: &ChampSelectSessionPayload) -> HashMap<i64, usize> {
+    let allies: HashSet<i64> = session.myTeam.iter().map(|p| p.cellId).collect();
+    let mut order = HashMap::new();
+
+    for action in session.actions.iter().flatten() {
+        if action.action_type == "pick" && allies.contains(&action.actorCellId) {
+            let next = order.len();
+            order.entry(action.actorCellId).or_insert(next);
+        }
+    }
+
+    order
+}
@@ -1,9 +1,27 @@
 use crate::adapters::outbound::data_dragon_champion_resolver::DataDragonChampionResolver;
+use crate::adapters::outbound::dry_run_lcu_api::{DryRunLcuApi, RecordedIntent};
+use crate::adapters::outbound::jsonl_champ_select_history::JsonlChampSelectHistory;
+use crate::adapters::outbound::lcu_skin_catalog::LcuSkinCatalog;
+use crate::domain::champ_select::decision_reporter::{DecisionReport, DecisionReporter};
+use crate::domain::champ_select::history::{ChampSelectRecord, HistoryQuery};
+use crate::domain::champ_select::matchup_table::{MatchupRow, MatchupTable};
+use crate::domain::client_rune_page::{ClientPageSelection, ClientRunePage};
 use crate::domain::feature::{FeatureId, FeatureInfo};
 use crate::domain::feature_manager::FeatureManager;
-use crate::domain::rune_page::RunePage;
-use crate::domain::rune_page_manager::RunePageManager;
+use crate::domain::item_set::ItemSet;
+use crate::domain::item_set_manager::ItemSetManager;
+use crate::domain::match_stats::{ChampionStatsRow, MatchStats};
+use crate::domain::ports::{ChampSelectHistoryPort, ChampionResolverPort, SkinCatalogPort};
+use crate::domain::role_resolver::RoleResolver;
+use crate::domain::rune_page::{ContextChampion, RunePage, RunePageChoice, RunePageContext};
+use crate::domain::rune_catalogue::RuneValidationError;
+use crate::domain::rune_page_manager::{RunePageError, RunePageManager};
+use crate::domain::rune_page_transfer::{
+    decode_share_code, parse_export, ImportConflict, ImportReport,
+};
+use crate::domain::skin::Skin;
 use serde_json::Value;
+use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
 use tauri::{AppHandle, State};
@@ -59,9 +77,81 @@ pub async fn list_champions(
 }
 
 #[tauri::command]
-pub fn save_rune_page(state: State<Arc<RunePageManager>>, page: RunePage) -> Result<(), String> {
+pub async fn save_rune_page(
+    state: State<'_, Arc<RunePageManager>>,
+    page: RunePage,
+) -> Result<(), RunePageError> {
     log::info!("Saving rune page {}", page.name);
-    state.save(page)
+    state.save(page).await
+}
+
+#[tauri::command]
+pub async fn validate_rune_page(
+    state: State<'_, Arc<RunePageManager>>,
+    page: RunePage,
+) -> Result<Vec<RuneValidationError>, String> {
+    Ok(state.validate(&page).await)
+}
+
+#[tauri::command]
+pub fn export_rune_pages(
+    state: State<Arc<RunePageManager>>,
+    ids: Option<Vec<String>>,
+) -> Result<String, String> {
+    log::info!("Exporting rune pages {:?}", ids);
+    state.export(ids.as_deref())
+}
+
+#[tauri::command]
+pub async fn import_rune_pages(
+    state: State<'_, Arc<RunePageManager>>,
+    content: String,
+    conflict: ImportConflict,
+) -> Result<ImportReport, String> {
+    log::info!("Importing rune pages ({:?} on conflict)", conflict);
+    state.import(parse_export(&content)?, conflict).await
+}
+
+#[tauri::command]
+pub async fn list_client_rune_pages(
+    state: State<'_, Arc<RunePageManager>>,
+    api: State<'_, Arc<DryRunLcuApi>>,
+) -> Result<Vec<ClientRunePage>, String> {
+    log::info!("Listing client rune pages");
+    state.client_pages(api.inner().as_ref()).await
+}
+
+#[tauri::command]
+pub async fn import_client_rune_pages(
+    state: State<'_, Arc<RunePageManager>>,
+    api: State<'_, Arc<DryRunLcuApi>>,
+    selections: Vec<ClientPageSelection>,
+    conflict: ImportConflict,
+) -> Result<ImportReport, String> {
+    log::info!(
+        "Importing {} client rune pages ({:?} on conflict)",
+        selections.len(),
+        conflict
+    );
+    state
+        .import_from_client(api.inner().as_ref(), selections, conflict)
+        .await
+}
+
+#[tauri::command]
+pub fn get_rune_share_code(state: State<Arc<RunePageManager>>, id: String) -> Result<String, String> {
+    log::info!("Creating share code for rune page {}", id);
+    state.share_code(&id)
+}
+
+#[tauri::command]
+pub async fn import_rune_share_code(
+    state: State<'_, Arc<RunePageManager>>,
+    code: String,
+    conflict: ImportConflict,
+) -> Result<ImportReport, String> {
+    log::info!("Importing rune share code ({:?} on conflict)", conflict);
+    state.import(vec![decode_share_code(&code)?], conflict).await
 }
 
 #[tauri::command]
@@ -70,12 +160,189 @@ pub fn list_rune_pages(state: State<Arc<RunePageManager>>) -> Vec<RunePage> {
     state.list()
 }
 
+#[tauri::command]
+pub fn get_default_rune_page(state: State<Arc<RunePageManager>>) -> Option<String> {
+    state.default_page_id()
+}
+
+#[tauri::command]
+pub fn set_default_rune_page(
+    state: State<Arc<RunePageManager>>,
+    id: Option<String>,
+) -> Result<(), String> {
+    log::info!("Setting default rune page {:?}", id);
+    state.set_default_page(id.as_deref())
+}
+
+/// The page champ select would apply, with the rule that chose it. The lane
+/// opponent and allies are unknown here, so conditions on them never hold.
+#[tauri::command]
+pub async fn preview_rune_page(
+    state: State<'_, Arc<RunePageManager>>,
+    resolver: State<'_, DataDragonChampionResolver>,
+    champion: String,
+    role: Option<String>,
+    queue_id: Option<i64>,
+) -> Result<Option<RunePageChoice>, String> {
+    log::info!(
+        "Previewing rune page for {} ({:?}) in queue {:?}",
+        champion,
+        role,
+        queue_id
+    );
+
+    let tags = match resolver.resolve_id(&champion).await {
+        Some(id) => resolver.resolve_tags(id).await,
+        None => Vec::new(),
+    };
+    let champion = ContextChampion {
+        name: champion,
+        tags,
+    };
+    let ctx = RunePageContext {
+        queue_id: queue_id.unwrap_or_default(),
+        ..RunePageContext::default()
+    };
+
+    Ok(state.find_best_page(&champion, role.as_deref(), &ctx))
+}
+
 #[tauri::command]
 pub fn delete_rune_page(state: State<Arc<RunePageManager>>, id: String) -> Result<(), String> {
     log::info!("Deleting rune page {}", id);
     state.delete(&id)
 }
 
+#[tauri::command]
+pub async fn save_item_set(
+    state: State<'_, Arc<ItemSetManager>>,
+    set: ItemSet,
+) -> Result<(), String> {
+    log::info!("Saving item set {}", set.title);
+    state.save(set).await
+}
+
+#[tauri::command]
+pub fn list_item_sets(state: State<Arc<ItemSetManager>>) -> Vec<ItemSet> {
+    log::info!("Listing item sets");
+    state.list()
+}
+
+#[tauri::command]
+pub fn delete_item_set(state: State<Arc<ItemSetManager>>, id: String) -> Result<(), String> {
+    log::info!("Deleting item set {}", id);
+    state.delete(&id)
+}
+
+#[tauri::command]
+pub async fn import_matchups(
+    state: State<'_, Arc<MatchupTable>>,
+    content: String,
+    format: String,
+    replace: bool,
+) -> Result<usize, String> {
+    log::info!("Importing matchups ({}, replace={})", format, replace);
+    state.import(&content, &format, replace).await
+}
+
+#[tauri::command]
+pub fn list_matchups(state: State<Arc<MatchupTable>>) -> Vec<MatchupRow> {
+    log::info!("Listing matchups");
+    state.list()
+}
+
+#[tauri::command]
+pub fn get_queue_roles(state: State<Arc<RoleResolver>>) -> HashMap<String, String> {
+    log::info!("Getting queue default roles");
+    state.queue_defaults()
+}
+
+#[tauri::command]
+pub fn set_queue_roles(
+    state: State<Arc<RoleResolver>>,
+    roles: HashMap<String, String>,
+) -> Result<(), String> {
+    log::info!("Setting queue default roles: {:?}", roles);
+    state.set_queue_defaults(roles)
+}
+
+#[tauri::command]
+pub fn set_session_role(state: State<Arc<RoleResolver>>, role: Option<String>) -> Result<(), String> {
+    log::info!("Overriding session role: {:?}", role);
+    state.set_session_role(role.as_deref())
+}
+
+#[tauri::command]
+pub fn get_last_decision(state: State<Arc<DecisionReporter>>) -> Option<DecisionReport> {
+    state.last()
+}
+
+#[tauri::command]
+pub async fn query_champ_select_history(
+    state: State<'_, Arc<JsonlChampSelectHistory>>,
+    query: Option<HistoryQuery>,
+) -> Result<Vec<ChampSelectRecord>, String> {
+    log::info!("Querying champ select history: {:?}", query);
+    let history = state.inner().clone();
+
+    // Reads and parses the whole file; keep it off the async workers.
+    tokio::task::spawn_blocking(move || history.query(&query.unwrap_or_default()))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn list_champion_stats(
+    state: State<'_, Arc<MatchStats>>,
+    role: Option<String>,
+    queue_id: Option<i64>,
+) -> Result<Vec<ChampionStatsRow>, String> {
+    log::info!("Listing champion stats (role={:?}, queue={:?})", role, queue_id);
+    Ok(state.table(role.as_deref(), queue_id))
+}
+
+#[tauri::command]
+pub async fn refresh_match_stats(state: State<'_, Arc<MatchStats>>) -> Result<usize, String> {
+    log::info!("Refreshing match stats");
+    state.refresh().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_dry_run(state: State<Arc<DryRunLcuApi>>) -> bool {
+    state.is_enabled()
+}
+
+#[tauri::command]
+pub fn set_dry_run(state: State<Arc<DryRunLcuApi>>, enabled: bool) {
+    log::info!("Setting dry run: {}", enabled);
+    state.set_enabled(enabled);
+}
+
+#[tauri::command]
+pub fn list_dry_run_intents(state: State<Arc<DryRunLcuApi>>) -> Vec<RecordedIntent> {
+    log::info!("Listing dry run intents");
+    state.intents()
+}
+
+#[tauri::command]
+pub fn clear_dry_run_intents(state: State<Arc<DryRunLcuApi>>) {
+    log::info!("Clearing dry run intents");
+    state.clear();
+}
+
+#[tauri::command]
+pub async fn list_owned_skins(
+    catalog: State<'_, Arc<LcuSkinCatalog>>,
+    champion_id: i32,
+) -> Result<Vec<Skin>, String> {
+    log::info!("Listing owned skins for champion {}", champion_id);
+    catalog
+        .owned_skins(champion_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[derive(Clone, serde::Serialize)]
 #[serde(tag = "event", content = "data")]
 pub enum UpdateEvent {
@@ -0,0 +1,18 @@
+use std::collections::HashSet;
+
+/// Which champions the client will accept for the current session.
+/// Each set is `None` when the client could not be asked, in which case
+/// nothing is filtered on it.
+#[derive(Debug, Clone, Default)]
+pub struct ChampionAvailability {
+    pub pickable: Option<HashSet<i32>>,
+    pub bannable: Option<HashSet<i32>>,
+    pub owned: Option<HashSet<i32>>,
+}
+
+impl ChampionAvailability {
+    /// True when every set was fetched, so the result is worth caching.
+    pub fn is_complete(&self) -> bool {
+        self.pickable.is_some() && self.bannable.is_some() && self.owned.is_some()
+    }
+}
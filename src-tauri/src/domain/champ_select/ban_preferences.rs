@@ -2,11 +2,14 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+use crate::domain::champ_select::queue::{self, ScopedRoleMap};
 use crate::domain::ports::ChampionResolverPort;
 use log::info;
 
+/// Champion lists keyed by (queue scope, role). A queue uses its own list,
+/// then its group's, then the `default` scope's.
 pub struct BanPreferences {
-    data: RwLock<HashMap<String, Vec<i32>>>,
+    data: RwLock<ScopedRoleMap<i32>>,
 }
 
 impl BanPreferences {
@@ -15,30 +18,35 @@ impl BanPreferences {
             data: RwLock::new(HashMap::new()),
         }
     }
-    pub async fn get_for_role(&self, role: &str) -> Vec<i32> {
+
+    pub async fn get_for(&self, queue_id: i64, role: &str) -> Vec<i32> {
         let guard = self.data.read().await;
-        guard.get(&role.to_lowercase()).cloned().unwrap_or_default()
+        queue::lookup(&guard, queue_id, role)
     }
 
     pub async fn resolve_from_names(
         &self,
-        role_to_names: HashMap<String, Vec<String>>,
+        scoped_names: ScopedRoleMap<String>,
         resolver: Arc<dyn ChampionResolverPort>,
     ) {
-        let mut resolved: HashMap<String, Vec<i32>> = HashMap::new();
+        let mut resolved: ScopedRoleMap<i32> = HashMap::new();
+
+        for (scope, role_map) in scoped_names {
+            let roles = resolved.entry(scope.to_lowercase()).or_default();
 
-        for (role, names) in role_to_names {
-            let mut ids = Vec::new();
+            for (role, names) in role_map {
+                let mut ids = Vec::new();
 
-            for name in names {
-                if let Some(id) = resolver.resolve_id(&name).await {
-                    ids.push(id);
-                } else {
-                    info!("Unknown champion in ban preferences: {}", name);
+                for name in names {
+                    if let Some(id) = resolver.resolve_id(&name).await {
+                        ids.push(id);
+                    } else {
+                        info!("Unknown champion in ban preferences: {}", name);
+                    }
                 }
-            }
 
-            resolved.insert(role.to_lowercase(), ids);
+                roles.insert(role.to_lowercase(), ids);
+            }
         }
 
         let mut guard = self.data.write().await;
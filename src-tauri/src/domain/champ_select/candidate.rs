@@ -0,0 +1,24 @@
+/// A champion a strategy considers viable, with a strategy-specific score.
+/// Higher scores are better; sources return candidates best first.
+#[derive(Debug, Clone, Copy)]
+pub struct Candidate {
+    pub champion_id: i32,
+    pub score: f64,
+}
+
+impl Candidate {
+    pub fn new(champion_id: i32, score: f64) -> Self {
+        Self { champion_id, score }
+    }
+}
+
+/// Scores a priority list so earlier entries rank higher.
+pub fn ranked(ids: impl IntoIterator<Item = i32>) -> Vec<Candidate> {
+    let ids: Vec<i32> = ids.into_iter().collect();
+    let n = ids.len();
+
+    ids.into_iter()
+        .enumerate()
+        .map(|(i, cid)| Candidate::new(cid, (n - i) as f64))
+        .collect()
+}
@@ -1,9 +1,10 @@
+use serde::{Deserialize, Serialize};
 use std::fmt::{Debug, Formatter};
 
-/// Base decision result used by pick & ban strategies
-pub enum Decision {
-    Choice(ChampionChoice),
-    None,
+/// Base decision result used by pick & ban strategies, with the reasoning behind it.
+pub struct Decision {
+    choice: Option<ChampionChoice>,
+    trace: DecisionTrace,
 }
 
 /// Represents a specific champion choice, like Python's ChampionChoice.
@@ -22,37 +23,112 @@ impl ChampionChoice {
     }
 }
 
-/// Helper to construct a ChampionChoice wrapped as a Decision
-pub fn choose(champion_id: i32) -> Decision {
-    Decision::Choice(ChampionChoice::new(champion_id))
+/// One champion a strategy looked at, and why it was passed over if it was.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsideredCandidate {
+    pub champion_id: i32,
+    pub score: Option<f64>,
+    pub rejected: Option<String>,
 }
 
-/// Helper to return "no decision"
-pub fn no_decision() -> Decision {
-    Decision::None
+/// How a strategy reached its decision.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecisionTrace {
+    /// Strategy name; fallbacks read as "counter → priority".
+    pub strategy: String,
+    pub candidates: Vec<ConsideredCandidate>,
+    /// The rule that produced the choice (or the lack of one).
+    pub rule: Option<String>,
+}
+
+impl DecisionTrace {
+    pub fn new(strategy: impl Into<String>) -> Self {
+        Self {
+            strategy: strategy.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn accept(&mut self, champion_id: i32, score: Option<f64>) {
+        self.candidates.push(ConsideredCandidate {
+            champion_id,
+            score,
+            rejected: None,
+        });
+    }
+
+    pub fn reject(&mut self, champion_id: i32, reason: impl Into<String>) {
+        self.candidates.push(ConsideredCandidate {
+            champion_id,
+            score: None,
+            rejected: Some(reason.into()),
+        });
+    }
+
+    /// Records a step taken on the way to the decision, such as a pipeline
+    /// fallback or filter. Steps lead the final rule.
+    pub fn note(&mut self, step: impl Into<String>) {
+        let step = step.into();
+        self.rule = Some(match self.rule.take() {
+            Some(steps) => format!("{steps}; {step}"),
+            None => step,
+        });
+    }
+
+    fn concluded(mut self, rule: impl Into<String>) -> Self {
+        self.note(rule);
+        self
+    }
+
+    pub fn choose(self, champion_id: i32, rule: impl Into<String>) -> Decision {
+        Decision {
+            choice: Some(ChampionChoice::new(champion_id)),
+            trace: self.concluded(rule),
+        }
+    }
+
+    pub fn nothing(self, rule: impl Into<String>) -> Decision {
+        Decision {
+            choice: None,
+            trace: self.concluded(rule),
+        }
+    }
 }
 
 impl Decision {
     pub fn has_choice(&self) -> bool {
-        matches!(self, Decision::Choice(_))
+        self.choice.is_some()
     }
 
     pub fn champion_id(&self) -> i32 {
-        match self {
-            Decision::Choice(c) => c.champion_id(),
-            Decision::None => 0,
-        }
+        self.choice.map(|c| c.champion_id()).unwrap_or(0)
+    }
+
+    pub fn trace(&self) -> &DecisionTrace {
+        &self.trace
+    }
+
+    /// Marks this decision as a fallback taken by `strategy`, and why.
+    pub fn via(mut self, strategy: &str, why: &str) -> Self {
+        self.trace.strategy = format!("{} → {}", strategy, self.trace.strategy);
+        self.trace.rule = Some(match self.trace.rule.take() {
+            Some(rule) => format!("{why}; {rule}"),
+            None => why.to_string(),
+        });
+        self
     }
 }
 
 impl Debug for Decision {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Decision::Choice(c) => f
+        match self.choice {
+            Some(c) => f
                 .debug_tuple("ChampionChoice")
                 .field(&c.champion_id())
                 .finish(),
-            Decision::None => write!(f, "NoDecision"),
+            None => write!(f, "NoDecision"),
         }
     }
 }
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+
+use crate::application::features::auto_pick_ban::ban_pick_request::BanPickRequest;
+use crate::domain::champ_select::decision::{Decision, DecisionTrace};
+use crate::domain::ports::UiEventPublisherPort;
+
+/// The latest pick or ban decision and how it was reached.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecisionReport {
+    pub game_id: i64,
+    /// "pick" or "ban".
+    pub action: String,
+    pub role: String,
+    pub champion_id: Option<i32>,
+    pub trace: DecisionTrace,
+}
+
+/// Every distinct decision of the current game, oldest first.
+#[derive(Default)]
+struct GameDecisions {
+    game_id: i64,
+    reports: Vec<DecisionReport>,
+}
+
+/// Keeps the decisions of the current session and pushes the last one to the
+/// frontend as `champ-select-decision` whenever it changes.
+pub struct DecisionReporter {
+    ui_pub: Arc<dyn UiEventPublisherPort>,
+    state: Mutex<GameDecisions>,
+}
+
+impl DecisionReporter {
+    pub fn new(ui_pub: Arc<dyn UiEventPublisherPort>) -> Self {
+        Self {
+            ui_pub,
+            state: Mutex::new(GameDecisions::default()),
+        }
+    }
+
+    pub fn report(&self, action: &str, req: &BanPickRequest, decision: &Decision) {
+        let report = DecisionReport {
+            game_id: req.game_id,
+            action: action.to_string(),
+            role: req.role.clone(),
+            champion_id: decision.has_choice().then(|| decision.champion_id()),
+            trace: decision.trace().clone(),
+        };
+
+        let mut state = self.state.lock().unwrap();
+        if state.game_id != report.game_id {
+            *state = GameDecisions {
+                game_id: report.game_id,
+                reports: Vec::new(),
+            };
+        }
+
+        // The hover refreshes on every session update; only changes are worth sending.
+        if state.reports.last() == Some(&report) {
+            return;
+        }
+
+        if let Ok(payload) = serde_json::to_value(&report) {
+            self.ui_pub.emit("champ-select-decision", payload);
+        }
+
+        state.reports.push(report);
+    }
+
+    pub fn last(&self) -> Option<DecisionReport> {
+        self.state.lock().unwrap().reports.last().cloned()
+    }
+
+    /// Decisions made during `game_id`, if it is the current game.
+    pub fn for_game(&self, game_id: i64) -> Vec<DecisionReport> {
+        let state = self.state.lock().unwrap();
+        if state.game_id == game_id {
+            state.reports.clone()
+        } else {
+            Vec::new()
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::champ_select::decision_reporter::DecisionReport;
+use crate::domain::champ_select::models::{ChampSelectSessionPayload, CsPlayer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChampSelectOutcome {
+    /// The game loaded after champ select.
+    Started,
+    /// Champ select ended without a game (someone dodged or left).
+    Dodged,
+}
+
+/// Bans and picks of one team.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamDraft {
+    pub bans: Vec<i32>,
+    pub picks: Vec<i32>,
+}
+
+/// One of our own actions and whether Overseer set the champion it ended on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TakenAction {
+    pub action_id: i64,
+    /// "pick" or "ban".
+    pub kind: String,
+    pub champion_id: i32,
+    pub completed: bool,
+    pub by_overseer: bool,
+}
+
+/// Everything worth keeping about one champ select.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChampSelectRecord {
+    pub game_id: i64,
+    pub queue_id: i64,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub role: Option<String>,
+    pub my_champion: Option<i32>,
+    pub my_team: TeamDraft,
+    pub their_team: TeamDraft,
+    pub actions: Vec<TakenAction>,
+    #[serde(default)]
+    pub decisions: Vec<DecisionReport>,
+    pub rune_page: Option<String>,
+    pub outcome: ChampSelectOutcome,
+}
+
+impl ChampSelectRecord {
+    /// Builds a record from the last session seen; role, decisions and rune page
+    /// are filled in by the caller. `by_overseer(action, champion)` tells whether
+    /// we set that champion on the action.
+    pub fn from_session(
+        session: &ChampSelectSessionPayload,
+        started_at: DateTime<Utc>,
+        outcome: ChampSelectOutcome,
+        by_overseer: impl Fn(i64, i64) -> bool,
+    ) -> Self {
+        let mine = |cell: i64| session.myTeam.iter().any(|p| p.cellId == cell);
+
+        let mut my_team = TeamDraft::default();
+        let mut their_team = TeamDraft::default();
+        let mut actions = Vec::new();
+
+        for action in session.actions.iter().flatten() {
+            if action.actorCellId == session.localPlayerCellId && action.championId != 0 {
+                actions.push(TakenAction {
+                    action_id: action.id,
+                    kind: action.action_type.clone(),
+                    champion_id: action.championId as i32,
+                    completed: action.completed,
+                    by_overseer: by_overseer(action.id, action.championId),
+                });
+            }
+
+            if action.action_type == "ban" && action.completed && action.championId != 0 {
+                let team = if mine(action.actorCellId) {
+                    &mut my_team
+                } else {
+                    &mut their_team
+                };
+                team.bans.push(action.championId as i32);
+            }
+        }
+
+        my_team.picks = picks(&session.myTeam);
+        their_team.picks = picks(&session.theirTeam);
+
+        Self {
+            game_id: session.gameId,
+            queue_id: session.queueId,
+            started_at,
+            ended_at: Utc::now(),
+            role: None,
+            my_champion: session
+                .local_player()
+                .map(|p| p.championId as i32)
+                .filter(|c| *c != 0),
+            my_team,
+            their_team,
+            actions,
+            decisions: Vec::new(),
+            rune_page: None,
+            outcome,
+        }
+    }
+}
+
+fn picks(team: &[CsPlayer]) -> Vec<i32> {
+    team.iter()
+        .filter(|p| p.championId != 0)
+        .map(|p| p.championId as i32)
+        .collect()
+}
+
+/// Filters for reviewing past drafts; unset fields match everything.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryQuery {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    /// Matches the champion we ended champ select on.
+    pub champion_id: Option<i32>,
+    pub role: Option<String>,
+    pub outcome: Option<ChampSelectOutcome>,
+    pub limit: Option<usize>,
+}
+
+impl HistoryQuery {
+    pub fn matches(&self, r: &ChampSelectRecord) -> bool {
+        self.from.is_none_or(|from| r.ended_at >= from)
+            && self.to.is_none_or(|to| r.ended_at <= to)
+            && self.champion_id.is_none_or(|c| r.my_champion == Some(c))
+            && self.role.as_ref().is_none_or(|role| {
+                r.role
+                    .as_ref()
+                    .is_some_and(|r| r.eq_ignore_ascii_case(role))
+            })
+            && self.outcome.is_none_or(|o| r.outcome == o)
+    }
+}
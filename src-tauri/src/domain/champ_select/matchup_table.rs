@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tauri::Wry;
+use tauri_plugin_store::Store;
+use tokio::sync::RwLock;
+
+use crate::domain::ports::ChampionResolverPort;
+use log::info;
+
+/// One user-maintained matchup: how well `champion` does into `opponent`.
+/// Higher scores are better for `champion`; the scale is up to the user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchupRow {
+    pub champion: String,
+    pub opponent: String,
+    pub score: f64,
+}
+
+pub struct MatchupTable {
+    store: Arc<Store<Wry>>,
+    resolver: Arc<dyn ChampionResolverPort>,
+    /// (champion id, opponent id) → score, built lazily from the stored rows.
+    resolved: RwLock<Option<HashMap<(i32, i32), f64>>>,
+}
+
+impl MatchupTable {
+    pub fn new(store: Arc<Store<Wry>>, resolver: Arc<dyn ChampionResolverPort>) -> Self {
+        Self {
+            store,
+            resolver,
+            resolved: RwLock::new(None),
+        }
+    }
+
+    fn load_rows(&self) -> Vec<MatchupRow> {
+        self.store
+            .get("matchups")
+            .and_then(|v| v.as_array().cloned())
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|v| serde_json::from_value(v).ok())
+            .collect()
+    }
+
+    fn save_rows(&self, rows: &[MatchupRow]) {
+        let json: Vec<Value> = rows
+            .iter()
+            .map(|r| serde_json::to_value(r).unwrap())
+            .collect();
+
+        self.store.set("matchups", Value::Array(json));
+    }
+
+    pub fn list(&self) -> Vec<MatchupRow> {
+        self.load_rows()
+    }
+
+    /// Imports rows from JSON (`[{champion, opponent, score}]`) or CSV
+    /// (`champion,opponent,score`, optional header). Returns the number of rows imported.
+    pub async fn import(&self, content: &str, format: &str, replace: bool) -> Result<usize, String> {
+        let incoming = match format.to_lowercase().as_str() {
+            "json" => serde_json::from_str::<Vec<MatchupRow>>(content)
+                .map_err(|e| format!("invalid matchup JSON: {e}"))?,
+            "csv" => parse_csv(content)?,
+            other => return Err(format!("unsupported matchup format: {other}")),
+        };
+
+        let mut unknown = Vec::new();
+        for row in &incoming {
+            for name in [&row.champion, &row.opponent] {
+                if self.resolver.resolve_id(name).await.is_none() && !unknown.contains(name) {
+                    unknown.push(name.clone());
+                }
+            }
+        }
+
+        if !unknown.is_empty() {
+            return Err(format!("unknown champions: {}", unknown.join(", ")));
+        }
+
+        let mut rows = if replace { Vec::new() } else { self.load_rows() };
+
+        for row in incoming.iter().cloned() {
+            let existing = rows.iter_mut().find(|r| {
+                r.champion.eq_ignore_ascii_case(&row.champion)
+                    && r.opponent.eq_ignore_ascii_case(&row.opponent)
+            });
+
+            match existing {
+                Some(r) => *r = row,
+                None => rows.push(row),
+            }
+        }
+
+        self.save_rows(&rows);
+        *self.resolved.write().await = None;
+
+        Ok(incoming.len())
+    }
+
+    pub async fn score(&self, champion_id: i32, opponent_id: i32) -> Option<f64> {
+        {
+            let guard = self.resolved.read().await;
+            if let Some(map) = &*guard {
+                return map.get(&(champion_id, opponent_id)).copied();
+            }
+        }
+
+        let mut map = HashMap::new();
+        let mut complete = true;
+        for row in self.load_rows() {
+            let champion = self.resolver.resolve_id(&row.champion).await;
+            let opponent = self.resolver.resolve_id(&row.opponent).await;
+
+            match (champion, opponent) {
+                (Some(c), Some(o)) => {
+                    map.insert((c, o), row.score);
+                }
+                _ => {
+                    complete = false;
+                    info!(
+                        "⚠ Unknown champion in matchup {} vs {}",
+                        row.champion, row.opponent
+                    );
+                }
+            }
+        }
+
+        let score = map.get(&(champion_id, opponent_id)).copied();
+        // Import rejects unknown names, so a miss means champion data was
+        // unavailable; resolve again next time rather than cache a partial table.
+        if complete {
+            *self.resolved.write().await = Some(map);
+        }
+        score
+    }
+}
+
+fn parse_csv(content: &str) -> Result<Vec<MatchupRow>, String> {
+    let mut rows = Vec::new();
+
+    for (n, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let cols: Vec<&str> = line.split(',').map(|c| c.trim()).collect();
+        if cols.len() != 3 {
+            return Err(format!("line {}: expected 3 columns", n + 1));
+        }
+
+        let score = match cols[2].parse::<f64>() {
+            Ok(s) => s,
+            // Header row
+            Err(_) if n == 0 => continue,
+            Err(_) => return Err(format!("line {}: invalid score '{}'", n + 1, cols[2])),
+        };
+
+        rows.push(MatchupRow {
+            champion: cols[0].to_string(),
+            opponent: cols[1].to_string(),
+            score,
+        });
+    }
+
+    Ok(rows)
+}
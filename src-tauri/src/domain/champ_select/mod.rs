@@ -1,3 +1,10 @@
+pub mod availability;
 pub mod ban_preferences;
+pub mod candidate;
 pub mod decision;
+pub mod decision_reporter;
+pub mod history;
+pub mod matchup_table;
 pub mod models;
+pub mod queue;
+pub mod session_control;
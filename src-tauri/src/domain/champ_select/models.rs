@@ -26,11 +26,33 @@ pub struct CsPlayer {
     pub cellId: i64,
     pub championId: i64,
     pub assignedPosition: Option<String>,
+    pub puuid: Option<String>,
+    pub gameName: Option<String>,
+    pub tagLine: Option<String>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize)]
+pub struct CsBenchChampion {
+    pub championId: i64,
+    #[serde(default)]
+    pub isPriority: bool,
+}
+
+/// An incoming or outgoing champion trade or pick-order swap.
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize)]
+pub struct CsSwapRequest {
+    pub id: i64,
+    pub cellId: i64,
+    pub state: String,
 }
 
 #[allow(non_snake_case)]
 #[derive(Debug, Deserialize)]
 pub struct ChampSelectSessionPayload {
+    #[serde(default)]
+    pub gameId: i64,
     pub actions: Vec<Vec<CsAction>>,
     pub myTeam: Vec<CsPlayer>,
     pub theirTeam: Vec<CsPlayer>,
@@ -39,4 +61,36 @@ pub struct ChampSelectSessionPayload {
     pub queueId: i64,
 
     pub timer: CsTimer,
+
+    #[serde(default)]
+    pub benchEnabled: bool,
+    #[serde(default)]
+    pub benchChampions: Vec<CsBenchChampion>,
+
+    #[serde(default)]
+    pub trades: Vec<CsSwapRequest>,
+    #[serde(default)]
+    pub pickOrderSwaps: Vec<CsSwapRequest>,
+}
+
+impl ChampSelectSessionPayload {
+    pub fn local_player(&self) -> Option<&CsPlayer> {
+        self.myTeam
+            .iter()
+            .find(|p| p.cellId == self.localPlayerCellId)
+    }
+
+    /// Champion of the local player once their pick action is completed.
+    pub fn locked_champion(&self) -> Option<i64> {
+        self.actions
+            .iter()
+            .flatten()
+            .find(|a| {
+                a.action_type == "pick"
+                    && a.actorCellId == self.localPlayerCellId
+                    && a.completed
+                    && a.championId != 0
+            })
+            .map(|a| a.championId)
+    }
 }
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+/// Scope key for lists that apply to every queue.
+pub const DEFAULT_SCOPE: &str = "default";
+
+/// scope (queue id, queue group or `default`) → role → list.
+pub type ScopedRoleMap<T> = HashMap<String, HashMap<String, Vec<T>>>;
+
+/// Named group a queue belongs to, so one list can cover related queues.
+/// Swiftplay (480) and Quickplay (490) are separate modes with their own
+/// pick rules, so each gets its own group.
+pub fn queue_group(queue_id: i64) -> Option<&'static str> {
+    match queue_id {
+        420 | 440 => Some("ranked"),
+        400 => Some("draft"),
+        430 => Some("blind"),
+        480 => Some("swiftplay"),
+        490 => Some("quickplay"),
+        700 | 720 => Some("clash"),
+        _ => None,
+    }
+}
+
+/// Scope keys to try for a queue, most specific first:
+/// the queue id, then its group, then `default`.
+pub fn scope_keys(queue_id: i64) -> Vec<String> {
+    let mut keys = vec![queue_id.to_string()];
+
+    if let Some(group) = queue_group(queue_id) {
+        keys.push(group.to_string());
+    }
+
+    keys.push(DEFAULT_SCOPE.to_string());
+    keys
+}
+
+/// List for the most specific scope that has one for `role`.
+pub fn lookup<T: Clone>(map: &ScopedRoleMap<T>, queue_id: i64, role: &str) -> Vec<T> {
+    let role = role.to_lowercase();
+
+    scope_keys(queue_id)
+        .iter()
+        .find_map(|scope| map.get(scope)?.get(&role))
+        .cloned()
+        .unwrap_or_default()
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::domain::champ_select::models::ChampSelectSessionPayload;
+
+/// The client showed a champion on one of our actions that we never set.
+#[derive(Debug, Clone)]
+pub struct ManualOverride {
+    pub game_id: i64,
+    pub action_id: i64,
+    /// What we last set on the action; `None` when the user got there first.
+    pub expected: Option<i64>,
+    pub actual: i64,
+}
+
+#[derive(Default)]
+struct GameControl {
+    game_id: i64,
+    /// Action id → every champion we set on it, last one at the end.
+    set_by_bot: HashMap<i64, Vec<i64>>,
+    user_controlled: bool,
+}
+
+/// Remembers what auto pick/ban set on each action, so a champion the user
+/// chose by hand hands the rest of that champ select back to them.
+pub struct SessionControl {
+    state: Mutex<GameControl>,
+}
+
+impl SessionControl {
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(GameControl::default()),
+        }
+    }
+
+    fn for_game(state: &mut GameControl, game_id: i64) {
+        if state.game_id != game_id {
+            *state = GameControl {
+                game_id,
+                ..GameControl::default()
+            };
+        }
+    }
+
+    pub fn record(&self, game_id: i64, action_id: i64, champion_id: i64) {
+        let mut state = self.state.lock().unwrap();
+        Self::for_game(&mut state, game_id);
+        state
+            .set_by_bot
+            .entry(action_id)
+            .or_default()
+            .push(champion_id);
+    }
+
+    /// Whether we ever set `champion_id` on the action. Earlier values count
+    /// too, since session updates can trail our own PATCHes.
+    pub fn was_set_by_bot(&self, game_id: i64, action_id: i64, champion_id: i64) -> bool {
+        let state = self.state.lock().unwrap();
+        state.game_id == game_id
+            && state
+                .set_by_bot
+                .get(&action_id)
+                .is_some_and(|set| set.contains(&champion_id))
+    }
+
+    /// Whether the last champion we set on the action is `champion_id`.
+    pub fn last_set_is(&self, game_id: i64, action_id: i64, champion_id: i64) -> bool {
+        let state = self.state.lock().unwrap();
+        state.game_id == game_id
+            && state.set_by_bot.get(&action_id).and_then(|set| set.last()) == Some(&champion_id)
+    }
+
+    pub fn is_user_controlled(&self, game_id: i64) -> bool {
+        let state = self.state.lock().unwrap();
+        state.game_id == game_id && state.user_controlled
+    }
+
+    /// Compares our actions in the session against what we set. Returns the
+    /// override the first time one is seen; the game stays user-controlled after.
+    pub fn observe(&self, session: &ChampSelectSessionPayload) -> Option<ManualOverride> {
+        let mut state = self.state.lock().unwrap();
+        Self::for_game(&mut state, session.gameId);
+
+        if state.user_controlled {
+            return None;
+        }
+
+        let found = session
+            .actions
+            .iter()
+            .flatten()
+            .filter(|a| a.actorCellId == session.localPlayerCellId && a.championId != 0)
+            .find_map(|a| {
+                let ours = state.set_by_bot.get(&a.id);
+                if ours.is_some_and(|set| set.contains(&a.championId)) {
+                    return None;
+                }
+
+                // Includes champions hovered or locked before we set anything.
+                Some(ManualOverride {
+                    game_id: session.gameId,
+                    action_id: a.id,
+                    expected: ours.and_then(|set| set.last().copied()),
+                    actual: a.championId,
+                })
+            });
+
+        if found.is_some() {
+            state.user_controlled = true;
+        }
+
+        found
+    }
+}
@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::domain::rune_catalogue::{error, RuneCatalogue, RuneValidationError, SHARD_ROWS};
+use crate::domain::rune_page::{RunePage, RunePageConditions};
+use crate::domain::rune_page_transfer::new_page_id;
+
+/// A page as the client lists it under `/lol-perks/v1/pages`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientRunePage {
+    pub id: i64,
+    pub name: String,
+    pub primary_style_id: i32,
+    pub sub_style_id: i32,
+    pub selected_perk_ids: Vec<i32>,
+    pub is_editable: bool,
+    pub current: bool,
+}
+
+/// A client page the user chose to import, and what to attach it to.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientPageSelection {
+    pub page_id: i64,
+    #[serde(default)]
+    pub champions: Vec<String>,
+    pub role: Option<String>,
+}
+
+impl ClientRunePage {
+    pub fn from_json(v: &Value) -> Option<Self> {
+        let int = |key: &str| v.get(key).and_then(|n| n.as_i64());
+        let flag = |key: &str| v.get(key).and_then(|b| b.as_bool()).unwrap_or(false);
+
+        Some(Self {
+            id: int("id")?,
+            name: v
+                .get("name")
+                .and_then(|s| s.as_str())
+                .unwrap_or_default()
+                .to_string(),
+            primary_style_id: int("primaryStyleId").unwrap_or(0) as i32,
+            sub_style_id: int("subStyleId").unwrap_or(0) as i32,
+            selected_perk_ids: v
+                .get("selectedPerkIds")
+                .and_then(|a| a.as_array())
+                .map(|a| {
+                    a.iter()
+                        .filter_map(|n| n.as_i64())
+                        .map(|n| n as i32)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            is_editable: flag("isEditable"),
+            current: flag("current"),
+        })
+    }
+
+    /// Places the selected perks into primary, secondary and shard slots.
+    /// Secondary runes are slotted by row; shards keep the client's order,
+    /// since the same shard can sit in more than one row.
+    pub fn to_rune_page(
+        &self,
+        catalogue: &RuneCatalogue,
+        champions: Vec<String>,
+        role: Option<String>,
+    ) -> Result<RunePage, RuneValidationError> {
+        let primary = catalogue.tree(self.primary_style_id).ok_or_else(|| {
+            error(
+                "primary_tree_id",
+                "unknownTree",
+                format!("Unknown rune tree {}", self.primary_style_id),
+            )
+        })?;
+        let secondary = catalogue.tree(self.sub_style_id).ok_or_else(|| {
+            error(
+                "secondary_tree_id",
+                "unknownTree",
+                format!("Unknown rune tree {}", self.sub_style_id),
+            )
+        })?;
+
+        let mut primary_picks = HashMap::new();
+        let mut secondary_rows = Vec::new();
+        let mut shards = Vec::new();
+
+        for &perk in &self.selected_perk_ids {
+            if let Some(row) = primary.row_of(perk) {
+                primary_picks.insert(row as i32, Some(perk));
+            } else if let Some(row) = secondary.row_of(perk) {
+                secondary_rows.push((row, perk));
+            } else if SHARD_ROWS.iter().any(|row| row.contains(&perk)) {
+                shards.push(perk);
+            } else {
+                return Err(error(
+                    "selectedPerkIds",
+                    "unknownPerk",
+                    format!(
+                        "Rune {perk} is in neither {} nor {}",
+                        primary.name, secondary.name
+                    ),
+                ));
+            }
+        }
+
+        secondary_rows.sort_by_key(|(row, _)| *row);
+
+        Ok(RunePage {
+            id: new_page_id(),
+            name: self.name.clone(),
+            champions,
+            role,
+            primary_tree_id: Some(primary.id),
+            primary_picks,
+            secondary_tree_id: Some(secondary.id),
+            secondary_picks: secondary_rows
+                .into_iter()
+                .enumerate()
+                .map(|(slot, (_, perk))| (slot as i32, Some(perk)))
+                .collect(),
+            shards: shards
+                .into_iter()
+                .enumerate()
+                .map(|(slot, perk)| (slot as i32, Some(perk)))
+                .collect(),
+            conditions: RunePageConditions::default(),
+            priority: 0,
+        })
+    }
+}
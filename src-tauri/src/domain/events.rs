@@ -5,6 +5,7 @@ use serde_json::Value;
 pub enum EventType {
     All,
     ChampSelectSession,
+    ChatMessage,
     GameflowPhase,
     GameflowSession,
     PartiesNotification,
@@ -28,6 +28,12 @@ pub enum FeatureId {
     MatchReady,
     AutoPickBan,
     RunePicker,
+    SkinPicker,
+    ItemSets,
+    BenchSwap,
+    TradeResponder,
+    ChampSelectChat,
+    ChampSelectHistory,
 }
 
 impl fmt::Display for FeatureId {
@@ -38,6 +44,12 @@ impl fmt::Display for FeatureId {
             FeatureId::MatchReady => write!(f, "MatchReady"),
             FeatureId::AutoPickBan => write!(f, "AutoPickBan"),
             FeatureId::RunePicker => write!(f, "RunePicker"),
+            FeatureId::SkinPicker => write!(f, "SkinPicker"),
+            FeatureId::ItemSets => write!(f, "ItemSets"),
+            FeatureId::BenchSwap => write!(f, "BenchSwap"),
+            FeatureId::TradeResponder => write!(f, "TradeResponder"),
+            FeatureId::ChampSelectChat => write!(f, "ChampSelectChat"),
+            FeatureId::ChampSelectHistory => write!(f, "ChampSelectHistory"),
         }
     }
 }
@@ -52,6 +64,12 @@ impl FromStr for FeatureId {
             "MatchReady" => Ok(FeatureId::MatchReady),
             "AutoPickBan" => Ok(FeatureId::AutoPickBan),
             "RunePicker" => Ok(FeatureId::RunePicker),
+            "SkinPicker" => Ok(FeatureId::SkinPicker),
+            "ItemSets" => Ok(FeatureId::ItemSets),
+            "BenchSwap" => Ok(FeatureId::BenchSwap),
+            "TradeResponder" => Ok(FeatureId::TradeResponder),
+            "ChampSelectChat" => Ok(FeatureId::ChampSelectChat),
+            "ChampSelectHistory" => Ok(FeatureId::ChampSelectHistory),
             _ => Err(anyhow::anyhow!("Unknown feature id: {}", s)),
         }
     }
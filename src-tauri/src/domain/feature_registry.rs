@@ -2,27 +2,50 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::domain::feature::{Feature, FeatureId};
+use crate::domain::item_set_manager::ItemSetManager;
+use crate::domain::match_stats::MatchStats;
+use crate::domain::role_resolver::RoleResolver;
 use crate::domain::ports::{
-    BannerPort, ChampionResolverPort, DataDragonApiPort, HovererPort, IngameEventPublisherPort,
-    LcuApiPort, LeagueEventPublisherPort, LogPublisherPort, PickerPort,
+    BannerPort, ChampSelectHistoryPort, ChampionAvailabilityPort, ChampionResolverPort, DataDragonApiPort, HovererPort,
+    IngameEventPublisherPort, LcuApiPort, LeagueEventPublisherPort, LockfilePort, LogPublisherPort,
+    PickerPort, SkinCatalogPort, UiEventPublisherPort,
 };
 use crate::domain::rune_page_manager::RunePageManager;
 
 use crate::application::features::auto_pick_ban::champion_preferences::ChampionPreferences;
+use crate::application::features::auto_pick_ban::counter_pick_strategy::CounterPickStrategy;
 use crate::application::features::auto_pick_ban::feature::AutoPickBanFeature;
+use crate::application::features::auto_pick_ban::performance_pick_strategy::PerformancePickStrategy;
 use crate::application::features::auto_pick_ban::priority_ban_strategy::PriorityBanStrategy;
 use crate::application::features::auto_pick_ban::priority_pick_strategy::PriorityPickStrategy;
+use crate::application::features::auto_pick_ban::reporting_strategy::ReportingStrategy;
+use crate::application::features::auto_pick_ban::role_strategy_selector::{
+    BanStrategySelector, RoleStrategySelector,
+};
+use crate::application::features::auto_pick_ban::scripted_strategy::ScriptedStrategy;
+use crate::application::features::auto_pick_ban::strategy_pipeline::PipelineBuilder;
+use crate::application::features::bench_swap::feature::BenchSwapFeature;
+use crate::application::features::champ_select_chat::feature::ChampSelectChatFeature;
+use crate::application::features::champ_select_history::feature::ChampSelectHistoryFeature;
 use crate::application::features::ingame_log::feature::IngameEventLogFeature;
+use crate::application::features::item_sets::feature::ItemSetFeature;
 use crate::application::features::league_log::feature::LeagueEventLogFeature;
 use crate::application::features::match_ready::feature::MatchReadyFeature;
 use crate::application::features::rune_picker::feature::RunePickerFeature;
+use crate::application::features::skin_picker::feature::SkinPickerFeature;
+use crate::application::features::trade_responder::feature::TradeResponderFeature;
 
 use crate::adapters::outbound::data_dragon_champion_resolver::DataDragonChampionResolver;
+use crate::adapters::outbound::lcu_champion_availability::LcuChampionAvailability;
 use crate::adapters::outbound::lcu_champion_banner::ChampionBanner;
 use crate::adapters::outbound::lcu_champion_hoverer::ChampionHoverer;
 use crate::adapters::outbound::lcu_champion_picker::ChampionPicker;
+use crate::adapters::outbound::lcu_chat::LcuChat;
 
 use crate::domain::champ_select::ban_preferences::BanPreferences;
+use crate::domain::champ_select::decision_reporter::DecisionReporter;
+use crate::domain::champ_select::matchup_table::MatchupTable;
+use crate::domain::champ_select::session_control::SessionControl;
 use crate::domain::delay_state::DelayState;
 
 pub struct FeatureRegistry {
@@ -35,10 +58,19 @@ impl FeatureRegistry {
         league_pub: Arc<dyn LeagueEventPublisherPort>,
         ingame_pub: Arc<dyn IngameEventPublisherPort>,
         log_pub: Arc<dyn LogPublisherPort>,
+        ui_pub: Arc<dyn UiEventPublisherPort>,
         lcu_api: Arc<dyn LcuApiPort>,
+        lockfile: Arc<dyn LockfilePort>,
         data_dragon_api: Arc<dyn DataDragonApiPort>,
         delay: Arc<DelayState>,
         rune_pages: Arc<RunePageManager>,
+        skins: Arc<dyn SkinCatalogPort>,
+        item_sets: Arc<ItemSetManager>,
+        matchups: Arc<MatchupTable>,
+        roles: Arc<RoleResolver>,
+        decisions: Arc<DecisionReporter>,
+        history: Arc<dyn ChampSelectHistoryPort>,
+        match_stats: Arc<MatchStats>,
     ) -> Self {
         let mut map = HashMap::<FeatureId, Arc<dyn Feature>>::new();
 
@@ -48,17 +80,75 @@ impl FeatureRegistry {
         let pick_prefs = Arc::new(ChampionPreferences::new());
         let ban_prefs = Arc::new(BanPreferences::new());
 
-        let pick_strategy = Arc::new(PriorityPickStrategy::new(pick_prefs.clone()));
-        let ban_strategy = Arc::new(PriorityBanStrategy::new(ban_prefs.clone()));
+        let priority_pick = Arc::new(PriorityPickStrategy::new(pick_prefs.clone()));
+        let counter_pick = Arc::new(CounterPickStrategy::new(
+            pick_prefs.clone(),
+            matchups.clone(),
+            priority_pick.clone(),
+        ));
+        let performance_pick = Arc::new(PerformancePickStrategy::new(
+            pick_prefs.clone(),
+            match_stats.clone(),
+        ));
+        let priority_ban = Arc::new(PriorityBanStrategy::new(ban_prefs.clone()));
+        let scripted = Arc::new(ScriptedStrategy::new(
+            pick_prefs.clone(),
+            ban_prefs.clone(),
+            resolver.clone(),
+            priority_pick.clone(),
+            priority_ban.clone(),
+        ));
+        let pick_strategy = Arc::new(RoleStrategySelector::new(
+            priority_pick.clone(),
+            counter_pick.clone(),
+            scripted.clone(),
+            performance_pick.clone(),
+        ));
+        // Ban pipelines win, then ban scripts; other roles use priority.
+        let ban_strategy = Arc::new(BanStrategySelector::new(scripted.clone()));
+        let pipelines = Arc::new(PipelineBuilder::new(
+            priority_pick.clone(),
+            priority_ban.clone(),
+            counter_pick.clone(),
+            performance_pick.clone(),
+            resolver.clone(),
+            matchups.clone(),
+            match_stats.clone(),
+        ));
+
+        let reported_pick = Arc::new(ReportingStrategy::new(
+            pick_strategy.clone(),
+            decisions.clone(),
+        ));
+        let reported_ban = Arc::new(ReportingStrategy::new(
+            ban_strategy.clone(),
+            decisions.clone(),
+        ));
+
+        let control = Arc::new(SessionControl::new());
+        let availability: Arc<dyn ChampionAvailabilityPort> =
+            Arc::new(LcuChampionAvailability::new(lcu_api.clone()));
 
-        let hoverer: Arc<dyn HovererPort> =
-            Arc::new(ChampionHoverer::new(lcu_api.clone(), pick_strategy.clone()));
+        let hoverer: Arc<dyn HovererPort> = Arc::new(ChampionHoverer::new(
+            lcu_api.clone(),
+            availability.clone(),
+            reported_pick.clone(),
+            control.clone(),
+        ));
 
-        let picker: Arc<dyn PickerPort> =
-            Arc::new(ChampionPicker::new(lcu_api.clone(), pick_strategy.clone()));
+        let picker: Arc<dyn PickerPort> = Arc::new(ChampionPicker::new(
+            lcu_api.clone(),
+            availability.clone(),
+            reported_pick.clone(),
+            control.clone(),
+        ));
 
-        let banner: Arc<dyn BannerPort> =
-            Arc::new(ChampionBanner::new(lcu_api.clone(), ban_strategy.clone()));
+        let banner: Arc<dyn BannerPort> = Arc::new(ChampionBanner::new(
+            lcu_api.clone(),
+            availability.clone(),
+            reported_ban.clone(),
+            control.clone(),
+        ));
 
         map.insert(
             FeatureId::LeagueLog,
@@ -93,6 +183,7 @@ impl FeatureRegistry {
                 delay.clone(),
                 rune_pages.clone(),
                 resolver.clone(),
+                roles.clone(),
             )),
         );
 
@@ -106,6 +197,80 @@ impl FeatureRegistry {
                 pick_prefs.clone(),
                 ban_prefs.clone(),
                 resolver.clone(),
+                pick_strategy.clone(),
+                ban_strategy.clone(),
+                scripted.clone(),
+                performance_pick.clone(),
+                pipelines.clone(),
+                control.clone(),
+                log_pub.clone(),
+                ui_pub.clone(),
+                roles.clone(),
+            )),
+        );
+
+        map.insert(
+            FeatureId::SkinPicker,
+            Arc::new(SkinPickerFeature::new(
+                league_pub.clone(),
+                lcu_api.clone(),
+                skins.clone(),
+                resolver.clone(),
+            )),
+        );
+
+        map.insert(
+            FeatureId::ItemSets,
+            Arc::new(ItemSetFeature::new(
+                league_pub.clone(),
+                lcu_api.clone(),
+                lockfile.clone(),
+                item_sets.clone(),
+                resolver.clone(),
+            )),
+        );
+
+        map.insert(
+            FeatureId::TradeResponder,
+            Arc::new(TradeResponderFeature::new(
+                league_pub.clone(),
+                lcu_api.clone(),
+                log_pub.clone(),
+                pick_prefs.clone(),
+                resolver.clone(),
+                roles.clone(),
+            )),
+        );
+
+        map.insert(
+            FeatureId::BenchSwap,
+            Arc::new(BenchSwapFeature::new(
+                league_pub.clone(),
+                lcu_api.clone(),
+                resolver.clone(),
+            )),
+        );
+
+        map.insert(
+            FeatureId::ChampSelectChat,
+            Arc::new(ChampSelectChatFeature::new(
+                league_pub.clone(),
+                lcu_api.clone(),
+                Arc::new(LcuChat::new(lcu_api.clone())),
+                resolver.clone(),
+                roles.clone(),
+            )),
+        );
+
+        map.insert(
+            FeatureId::ChampSelectHistory,
+            Arc::new(ChampSelectHistoryFeature::new(
+                league_pub.clone(),
+                lcu_api.clone(),
+                history.clone(),
+                roles.clone(),
+                control.clone(),
+                decisions.clone(),
             )),
         );
 
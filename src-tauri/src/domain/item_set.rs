@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemBlock {
+    pub title: String,
+    pub items: Vec<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemSet {
+    pub id: String,
+    pub title: String,
+    pub champions: Vec<String>,
+    /// Map ids the set applies to (11 = Summoner's Rift, 12 = Howling Abyss). Empty means any map.
+    pub map_ids: Vec<i32>,
+    pub blocks: Vec<ItemBlock>,
+}
+
+impl ItemSet {
+    /// Whether the id is safe to use in a file name: letters, digits and `-` only.
+    pub fn has_valid_id(&self) -> bool {
+        !self.id.is_empty()
+            && self
+                .id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-')
+    }
+
+    pub fn item_ids(&self) -> Vec<i32> {
+        let mut out: Vec<i32> = self
+            .blocks
+            .iter()
+            .flat_map(|b| b.items.iter().copied())
+            .collect();
+        out.sort();
+        out.dedup();
+        out
+    }
+
+    pub fn applies_to_map(&self, map_id: i32) -> bool {
+        self.map_ids.is_empty() || self.map_ids.contains(&map_id)
+    }
+}
@@ -0,0 +1,102 @@
+use crate::domain::item_set::ItemSet;
+use crate::domain::ports::ItemCatalogPort;
+use serde_json::Value;
+use std::sync::Arc;
+use tauri::Wry;
+use tauri_plugin_store::Store;
+
+pub struct ItemSetManager {
+    store: Arc<Store<Wry>>,
+    items: Arc<dyn ItemCatalogPort>,
+}
+
+impl ItemSetManager {
+    pub fn new(store: Arc<Store<Wry>>, items: Arc<dyn ItemCatalogPort>) -> Self {
+        Self { store, items }
+    }
+
+    fn load_sets(&self) -> Vec<ItemSet> {
+        self.store
+            .get("itemSets")
+            .and_then(|v| v.as_array().cloned())
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|v| serde_json::from_value(v).ok())
+            .collect()
+    }
+
+    fn save_sets(&self, sets: Vec<ItemSet>) -> Result<(), String> {
+        let json: Vec<Value> = sets
+            .into_iter()
+            .map(|s| serde_json::to_value(s).unwrap())
+            .collect();
+
+        self.store.set("itemSets", Value::Array(json));
+        Ok(())
+    }
+
+    async fn validate(&self, set: &ItemSet) -> Result<(), String> {
+        if !set.has_valid_id() {
+            return Err(format!(
+                "invalid item set id {:?}: use letters, digits and '-' only",
+                set.id
+            ));
+        }
+
+        let known = self
+            .items
+            .item_ids()
+            .await
+            .map_err(|e| format!("could not load item data: {e}"))?;
+
+        let unknown: Vec<String> = set
+            .item_ids()
+            .into_iter()
+            .filter(|id| !known.contains(id))
+            .map(|id| id.to_string())
+            .collect();
+
+        if !unknown.is_empty() {
+            return Err(format!("unknown item ids: {}", unknown.join(", ")));
+        }
+
+        Ok(())
+    }
+
+    pub async fn save(&self, set: ItemSet) -> Result<(), String> {
+        self.validate(&set).await?;
+
+        let mut sets = self.load_sets();
+        let id = set.id.clone();
+
+        if let Some(existing) = sets.iter_mut().find(|s| s.id == id) {
+            *existing = set;
+        } else {
+            sets.push(set);
+        }
+
+        self.save_sets(sets)
+    }
+
+    pub fn delete(&self, id: &str) -> Result<(), String> {
+        let sets = self
+            .load_sets()
+            .into_iter()
+            .filter(|s| s.id != id)
+            .collect::<Vec<_>>();
+
+        self.save_sets(sets)
+    }
+
+    pub fn list(&self) -> Vec<ItemSet> {
+        self.load_sets()
+    }
+
+    pub fn find_sets(&self, champion: &str, map_id: i32) -> Vec<ItemSet> {
+        self.load_sets()
+            .into_iter()
+            .filter(|s| s.champions.iter().any(|c| c == champion))
+            .filter(|s| s.applies_to_map(map_id))
+            .collect()
+    }
+}
@@ -2,6 +2,6 @@ use serde::Serialize;
 
 #[derive(Debug, Serialize, Clone)]
 pub struct LogEntry {
-    pub source: String, // "ingame" or "league"
+    pub source: String, // "ingame", "league", "trade" or "autopickban"
     pub payload: serde_json::Value,
 }
@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// The local player's line from one finished game.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchRecord {
+    pub game_id: i64,
+    pub queue_id: i64,
+    pub played_at: DateTime<Utc>,
+    pub champion_id: i32,
+    /// Normalized role ("top", "jungle", "mid", "bottom", "support"), when the game has lanes.
+    pub role: Option<String>,
+    pub win: bool,
+    pub kills: u32,
+    pub deaths: u32,
+    pub assists: u32,
+}
@@ -0,0 +1,266 @@
+use log::{info, warn};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+use tokio::sync::Mutex as AsyncMutex;
+
+use crate::domain::events::{EventType, LeagueEvent};
+use crate::domain::match_history::MatchRecord;
+use crate::domain::ports::{LeagueEventSubscriber, MatchHistoryPort};
+
+/// Games fetched on the first load; later refreshes only need the newest few.
+const INITIAL_FETCH: usize = 100;
+const REFRESH_FETCH: usize = 20;
+/// Games kept in memory, newest first.
+const MAX_MATCHES: usize = 200;
+/// Match history lags a little behind `EndOfGame`.
+const END_OF_GAME_DELAY: Duration = Duration::from_secs(10);
+/// Wait before retrying a failed initial load, doubled per failure up to the max.
+const LOAD_RETRY: Duration = Duration::from_secs(5);
+const LOAD_RETRY_MAX: Duration = Duration::from_secs(300);
+
+/// Games, wins and totals over a set of matches.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChampionStats {
+    pub games: u32,
+    pub wins: u32,
+    pub kills: u32,
+    pub deaths: u32,
+    pub assists: u32,
+}
+
+impl ChampionStats {
+    fn add(&mut self, m: &MatchRecord) {
+        self.games += 1;
+        self.wins += m.win as u32;
+        self.kills += m.kills;
+        self.deaths += m.deaths;
+        self.assists += m.assists;
+    }
+
+    pub fn win_rate(&self) -> Option<f64> {
+        (self.games > 0).then(|| self.wins as f64 / self.games as f64)
+    }
+
+    /// (kills + assists) / deaths, with deathless games counting deaths as 1.
+    pub fn kda(&self) -> Option<f64> {
+        (self.games > 0).then(|| (self.kills + self.assists) as f64 / self.deaths.max(1) as f64)
+    }
+}
+
+/// One row of the stats table: a champion in a role and queue.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChampionStatsRow {
+    pub champion_id: i32,
+    pub role: Option<String>,
+    pub queue_id: i64,
+    #[serde(flatten)]
+    pub stats: ChampionStats,
+    pub win_rate: Option<f64>,
+    pub kda: Option<f64>,
+}
+
+/// Personal champion statistics computed from the client's match history.
+/// Loads in the background and refreshes after every `EndOfGame`; reads only
+/// ever see what is cached, so pick decisions never wait on the client.
+pub struct MatchStats {
+    history: Arc<dyn MatchHistoryPort>,
+    /// Newest first; `None` until the first successful load.
+    matches: RwLock<Option<Vec<MatchRecord>>>,
+    refreshing: AsyncMutex<()>,
+}
+
+impl MatchStats {
+    pub fn new(history: Arc<dyn MatchHistoryPort>) -> Self {
+        Self {
+            history,
+            matches: RwLock::new(None),
+            refreshing: AsyncMutex::new(()),
+        }
+    }
+
+    /// Fetches recent games and merges them in. Returns the number of games known.
+    pub async fn refresh(&self) -> anyhow::Result<usize> {
+        self.refresh_including(None).await
+    }
+
+    /// Like `refresh`, but also fetches `game_id` by itself if the recent
+    /// list does not have it yet.
+    pub async fn refresh_including(&self, game_id: Option<i64>) -> anyhow::Result<usize> {
+        let _guard = self.refreshing.lock().await;
+
+        let loaded = self.matches.read().unwrap().is_some();
+        let count = if loaded { REFRESH_FETCH } else { INITIAL_FETCH };
+        let mut fresh = self.history.recent_matches(count).await?;
+
+        if let Some(id) = game_id.filter(|id| !fresh.iter().any(|m| m.game_id == *id)) {
+            match self.history.game(id).await {
+                Ok(Some(m)) => fresh.push(m),
+                Ok(None) => info!("[MatchStats] Game {} has no line for us", id),
+                Err(e) => warn!("[MatchStats] Failed to load game {}: {}", id, e),
+            }
+        }
+
+        let mut matches = self.matches.write().unwrap();
+        let all = matches.get_or_insert_with(Vec::new);
+
+        for m in fresh {
+            if !all.iter().any(|x| x.game_id == m.game_id) {
+                all.push(m);
+            }
+        }
+
+        all.sort_by_key(|m| std::cmp::Reverse(m.played_at));
+        all.truncate(MAX_MATCHES);
+
+        info!("[MatchStats] {} game(s) in history", all.len());
+        Ok(all.len())
+    }
+
+    /// Loads history until it succeeds, backing off between failures (the
+    /// client may not be running yet). Meant to run in the background.
+    pub async fn load(&self) {
+        let mut wait = LOAD_RETRY;
+
+        while self.matches.read().unwrap().is_none() {
+            match self.refresh().await {
+                Ok(_) => return,
+                Err(e) => {
+                    warn!(
+                        "[MatchStats] Failed to load match history, retrying in {:?}: {}",
+                        wait, e
+                    );
+                    tokio::time::sleep(wait).await;
+                    wait = (wait * 2).min(LOAD_RETRY_MAX);
+                }
+            }
+        }
+    }
+
+    /// Known games, newest first; empty until the first load finishes.
+    pub fn matches(&self) -> Vec<MatchRecord> {
+        self.matches.read().unwrap().clone().unwrap_or_default()
+    }
+
+    /// Stats for a champion, optionally narrowed to a role and queue.
+    pub fn stats_for(
+        &self,
+        champion_id: i32,
+        role: Option<&str>,
+        queue_id: Option<i64>,
+    ) -> ChampionStats {
+        let mut stats = ChampionStats::default();
+
+        for m in self.matches().iter().filter(|m| {
+            m.champion_id == champion_id
+                && role.is_none_or(|r| {
+                    m.role
+                        .as_deref()
+                        .is_some_and(|mr| mr.eq_ignore_ascii_case(r))
+                })
+                && queue_id.is_none_or(|q| m.queue_id == q)
+        }) {
+            stats.add(m);
+        }
+
+        stats
+    }
+
+    /// Stats per champion, role and queue, most played first.
+    pub fn table(&self, role: Option<&str>, queue_id: Option<i64>) -> Vec<ChampionStatsRow> {
+        let mut groups: HashMap<(i32, Option<String>, i64), ChampionStats> = HashMap::new();
+
+        for m in self.matches() {
+            if role.is_some_and(|r| {
+                !m.role
+                    .as_deref()
+                    .is_some_and(|mr| mr.eq_ignore_ascii_case(r))
+            }) || queue_id.is_some_and(|q| m.queue_id != q)
+            {
+                continue;
+            }
+
+            groups
+                .entry((m.champion_id, m.role.clone(), m.queue_id))
+                .or_default()
+                .add(&m);
+        }
+
+        let mut rows: Vec<ChampionStatsRow> = groups
+            .into_iter()
+            .map(|((champion_id, role, queue_id), stats)| ChampionStatsRow {
+                champion_id,
+                role,
+                queue_id,
+                win_rate: stats.win_rate(),
+                kda: stats.kda(),
+                stats,
+            })
+            .collect();
+
+        rows.sort_by(|a, b| {
+            b.stats
+                .games
+                .cmp(&a.stats.games)
+                .then(a.champion_id.cmp(&b.champion_id))
+        });
+        rows
+    }
+}
+
+/// Refreshes match stats once per `EndOfGame`.
+pub struct MatchStatsRefresher {
+    stats: Arc<MatchStats>,
+    last_phase: Mutex<String>,
+}
+
+impl MatchStatsRefresher {
+    pub fn new(stats: Arc<MatchStats>) -> Self {
+        Self {
+            stats,
+            last_phase: Mutex::new(String::new()),
+        }
+    }
+}
+
+impl LeagueEventSubscriber for MatchStatsRefresher {
+    fn on_event(&self, event: &LeagueEvent) {
+        if event.event_type != EventType::GameflowSession {
+            return;
+        }
+
+        let source = event.data.get("payload").unwrap_or(&event.data);
+        let phase = source.get("phase").and_then(Value::as_str).unwrap_or("");
+
+        {
+            let mut last = self.last_phase.lock().unwrap();
+            if *last == phase {
+                return;
+            }
+            *last = phase.to_string();
+        }
+
+        if phase != "EndOfGame" {
+            return;
+        }
+
+        let game_id = source
+            .get("gameData")
+            .and_then(|g| g.get("gameId"))
+            .and_then(Value::as_i64)
+            .filter(|id| *id != 0);
+
+        let stats = Arc::clone(&self.stats);
+        tokio::spawn(async move {
+            tokio::time::sleep(END_OF_GAME_DELAY).await;
+
+            if let Err(e) = stats.refresh_including(game_id).await {
+                warn!("[MatchStats] Refresh after game failed: {}", e);
+            }
+        });
+    }
+}
@@ -1,14 +1,23 @@
 pub(crate) mod champ_select;
+pub mod client_rune_page;
 pub mod delay_state;
 pub mod events;
 pub(crate) mod feature;
 pub(crate) mod feature_manager;
 pub mod feature_registry;
 pub(crate) mod ingame_event_publisher;
+pub mod item_set;
+pub mod item_set_manager;
 pub mod lcu_socket_frame;
+pub mod match_history;
+pub mod match_stats;
 pub mod league_event_publisher;
 pub(crate) mod log_entry;
 pub mod ports;
 pub mod registry;
+pub mod role_resolver;
+pub mod rune_catalogue;
 pub mod rune_page;
 pub mod rune_page_manager;
+pub mod rune_page_transfer;
+pub mod skin;
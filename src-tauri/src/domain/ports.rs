@@ -1,11 +1,20 @@
 use crate::application::features::auto_pick_ban::ban_pick_request::BanPickRequest;
-use crate::domain::champ_select::decision::Decision;
+use crate::domain::champ_select::availability::ChampionAvailability;
+use crate::domain::champ_select::candidate::Candidate;
+use crate::domain::champ_select::decision::{Decision, DecisionTrace};
+use crate::domain::champ_select::history::{ChampSelectRecord, HistoryQuery};
 use crate::domain::champ_select::models::{ChampSelectSessionPayload, CsAction};
 use crate::domain::events::LeagueEvent;
+use crate::domain::item_set::ItemSet;
 use crate::domain::log_entry::LogEntry;
+use crate::domain::match_history::MatchRecord;
+use crate::domain::rune_catalogue::RuneCatalogue;
+use crate::domain::skin::Skin;
 use anyhow::Result;
 use async_trait::async_trait;
 use serde_json::Value;
+use std::collections::HashSet;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 pub trait LeagueEventPublisherPort: Send + Sync {
@@ -18,6 +27,11 @@ pub trait LogPublisherPort: Send + Sync {
     fn publish(&self, entry: LogEntry);
 }
 
+/// Pushes named state changes to the frontend.
+pub trait UiEventPublisherPort: Send + Sync {
+    fn emit(&self, event: &str, payload: Value);
+}
+
 pub trait LeagueEventSubscriber: Send + Sync {
     fn on_event(&self, event: &LeagueEvent);
 }
@@ -35,6 +49,8 @@ pub trait IngameEventSubscriber: Send + Sync {
 
 pub trait LockfilePort: Send + Sync {
     fn read_lockfile(&self) -> anyhow::Result<LockfileData>;
+    /// League install directory, i.e. the folder holding the lockfile.
+    fn install_dir(&self) -> anyhow::Result<PathBuf>;
 }
 
 #[derive(Debug, Clone)]
@@ -77,15 +93,81 @@ pub trait BanStrategy: Send + Sync {
     async fn decide(&self, req: &BanPickRequest) -> Decision;
 }
 
+/// Produces ranked candidates instead of a single decision, so strategies can be composed.
+#[async_trait]
+pub trait CandidateSource: Send + Sync {
+    /// Available champions for the request, best first. Champions left out
+    /// are rejected on `trace` with the reason, and steps taken are noted.
+    async fn candidates(&self, req: &BanPickRequest, trace: &mut DecisionTrace) -> Vec<Candidate>;
+}
+
 #[async_trait]
 pub trait ChampionResolverPort: Send + Sync {
     async fn refresh_cache(&self);
     async fn resolve_id(&self, name: &str) -> Option<i32>;
     async fn resolve_name(&self, champ_id: i32) -> Option<String>;
+    /// Data Dragon key (e.g. `MonkeyKing`), used for file paths.
+    async fn resolve_key(&self, champ_id: i32) -> Option<String>;
+    /// Data Dragon class tags (e.g. `Mage`, `Assassin`).
+    async fn resolve_tags(&self, champ_id: i32) -> Vec<String>;
 }
 
 #[async_trait]
 pub trait DataDragonApiPort: Send + Sync {
     async fn get_versions(&self) -> Result<Vec<String>>;
     async fn get_champions_json(&self, version: &str) -> Result<Value>;
+    async fn get_items_json(&self, version: &str) -> Result<Value>;
+    async fn get_runes_json(&self, version: &str) -> Result<Value>;
+}
+
+#[async_trait]
+pub trait RuneCataloguePort: Send + Sync {
+    async fn catalogue(&self) -> Result<RuneCatalogue>;
+}
+
+#[async_trait]
+pub trait ChampionAvailabilityPort: Send + Sync {
+    /// Pickable, bannable and owned champions for the session, cached per game.
+    async fn availability(&self, session: &ChampSelectSessionPayload) -> ChampionAvailability;
+}
+
+#[async_trait]
+pub trait MatchHistoryPort: Send + Sync {
+    /// The current summoner's most recent games, newest first.
+    async fn recent_matches(&self, count: usize) -> Result<Vec<MatchRecord>>;
+    /// The current summoner's line in one game, if they played in it.
+    async fn game(&self, game_id: i64) -> Result<Option<MatchRecord>>;
+}
+
+/// Append-only store of finished champ selects.
+pub trait ChampSelectHistoryPort: Send + Sync {
+    fn append(&self, record: &ChampSelectRecord) -> Result<()>;
+    /// Matching records, newest first.
+    fn query(&self, query: &HistoryQuery) -> Result<Vec<ChampSelectRecord>>;
+}
+
+#[async_trait]
+pub trait ChatPort: Send + Sync {
+    /// Id of the champ select group chat, if one is open.
+    async fn champ_select_conversation(&self) -> Result<Option<String>>;
+    async fn send(&self, conversation_id: &str, body: &str) -> Result<()>;
+}
+
+#[async_trait]
+pub trait SkinCatalogPort: Send + Sync {
+    /// Skins (with owned chromas) the current summoner owns for a champion.
+    async fn owned_skins(&self, champion_id: i32) -> Result<Vec<Skin>>;
+    /// Unlocked skins offered by the champ select skin carousel.
+    async fn carousel_skins(&self) -> Result<Vec<Skin>>;
+}
+
+#[async_trait]
+pub trait ItemCatalogPort: Send + Sync {
+    async fn item_ids(&self) -> Result<HashSet<i32>>;
+}
+
+#[async_trait]
+pub trait ItemSetWriterPort: Send + Sync {
+    /// Replaces every Overseer-written set for the champion with `sets`.
+    async fn write(&self, champion_id: i32, champion_key: &str, sets: &[ItemSet]) -> Result<()>;
 }
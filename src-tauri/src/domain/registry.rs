@@ -30,6 +30,9 @@ impl EventRegistry {
             "/riot-messaging-service/v1/message/teambuilder/v1/tbdGameDtoV1" => {
                 EventType::TeambuilderTBDGame
             }
+            uri if uri.starts_with("/lol-chat/v1/conversations/") && uri.contains("/messages/") => {
+                EventType::ChatMessage
+            }
             _ => EventType::Unknown,
         }
     }
@@ -0,0 +1,247 @@
+use log::info;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tauri::Wry;
+use tauri_plugin_store::Store;
+
+use crate::application::features::auto_pick_ban::ban_pick_request::normalize_position;
+use crate::domain::champ_select::models::ChampSelectSessionPayload;
+use crate::domain::events::{EventType, LeagueEvent};
+use crate::domain::ports::LeagueEventSubscriber;
+
+/// Roles preferences and rune pages are keyed by.
+pub const ROLES: [&str; 5] = ["top", "jungle", "mid", "bottom", "support"];
+
+/// What we know about the current champ select beyond the session payload.
+#[derive(Default)]
+struct SessionRole {
+    game_id: i64,
+    /// Whether that champ select is still running.
+    active: bool,
+    local_puuid: Option<String>,
+    /// Role the local player called out in champ select chat.
+    called: Option<String>,
+    /// Role set through the `set_session_role` command.
+    overridden: Option<String>,
+    /// Override set outside champ select, for the next one.
+    pending: Option<String>,
+}
+
+/// Decides which role the local player is playing. In order: a manual
+/// override for the session, the assigned position, a role called out in
+/// champ select chat, then the configured default for the queue.
+pub struct RoleResolver {
+    store: Arc<Store<Wry>>,
+    session: Mutex<SessionRole>,
+}
+
+impl RoleResolver {
+    pub fn new(store: Arc<Store<Wry>>) -> Self {
+        Self {
+            store,
+            session: Mutex::new(SessionRole::default()),
+        }
+    }
+
+    /// Queue id → role for queues without assigned positions.
+    /// Until configured, queue 3140 defaults to support.
+    pub fn queue_defaults(&self) -> HashMap<String, String> {
+        self.store
+            .get("queueRoles")
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_else(|| HashMap::from([("3140".to_string(), "support".to_string())]))
+    }
+
+    pub fn set_queue_defaults(&self, defaults: HashMap<String, String>) -> Result<(), String> {
+        let mut clean = HashMap::new();
+        for (queue, role) in defaults {
+            queue
+                .parse::<i64>()
+                .map_err(|_| format!("invalid queue id '{queue}'"))?;
+            clean.insert(
+                queue,
+                parse_role(&role).ok_or(format!("unknown role '{role}'"))?,
+            );
+        }
+
+        self.store.set("queueRoles", json!(clean));
+        Ok(())
+    }
+
+    /// Overrides the role for the current champ select, or for the next one
+    /// when none is running; `None` clears it.
+    pub fn set_session_role(&self, role: Option<&str>) -> Result<(), String> {
+        let role = match role {
+            Some(r) => Some(parse_role(r).ok_or(format!("unknown role '{r}'"))?),
+            None => None,
+        };
+
+        let mut state = self.session.lock().unwrap();
+        if state.active {
+            info!("[RoleResolver] Session role override = {:?}", role);
+            state.overridden = role;
+        } else {
+            info!(
+                "[RoleResolver] Role override for the next champ select = {:?}",
+                role
+            );
+            state.pending = role;
+        }
+        Ok(())
+    }
+
+    /// Starts tracking `game_id`, carrying a pending override into it.
+    /// Game id 0 (not known yet) leaves the state alone.
+    fn enter_game(state: &mut SessionRole, game_id: i64) {
+        if game_id == 0 {
+            return;
+        }
+
+        if state.game_id != game_id {
+            *state = SessionRole {
+                game_id,
+                overridden: state.pending.take(),
+                ..SessionRole::default()
+            };
+        }
+        state.active = true;
+    }
+
+    pub fn resolve(&self, session: &ChampSelectSessionPayload) -> Option<String> {
+        let assigned = session
+            .myTeam
+            .iter()
+            .find(|p| p.cellId == session.localPlayerCellId)
+            .and_then(|p| p.assignedPosition.as_deref());
+
+        self.resolve_for(session.gameId, session.queueId, assigned)
+    }
+
+    pub fn resolve_for(
+        &self,
+        game_id: i64,
+        queue_id: i64,
+        assigned: Option<&str>,
+    ) -> Option<String> {
+        let (overridden, called) = {
+            let mut state = self.session.lock().unwrap();
+            Self::enter_game(&mut state, game_id);
+            (state.overridden.clone(), state.called.clone())
+        };
+
+        overridden
+            .or_else(|| normalize_position(assigned?))
+            .or(called)
+            .or_else(|| self.queue_defaults().get(&queue_id.to_string()).cloned())
+    }
+
+    fn observe_session(&self, session: &ChampSelectSessionPayload) {
+        let puuid = session
+            .myTeam
+            .iter()
+            .find(|p| p.cellId == session.localPlayerCellId)
+            .and_then(|p| p.puuid.clone());
+
+        let mut state = self.session.lock().unwrap();
+        Self::enter_game(&mut state, session.gameId);
+        state.local_puuid = puuid;
+    }
+
+    fn observe_chat(&self, event: &LeagueEvent) {
+        let uri = event
+            .raw
+            .payload
+            .get("uri")
+            .and_then(|v| v.as_str())
+            .unwrap_or("");
+
+        if !uri.contains("champ-select") {
+            return;
+        }
+
+        let msg = &event.data;
+        if msg.get("isHistorical").and_then(|v| v.as_bool()) == Some(true) {
+            return;
+        }
+
+        let Some(role) = msg
+            .get("body")
+            .and_then(|v| v.as_str())
+            .and_then(parse_callout)
+        else {
+            return;
+        };
+
+        let mut state = self.session.lock().unwrap();
+        let Some(puuid) = state.local_puuid.clone() else {
+            return;
+        };
+
+        if !from_player(msg, &puuid) {
+            return;
+        }
+
+        info!("[RoleResolver] Called out role {}", role);
+        state.called = Some(role);
+    }
+}
+
+fn from_player(msg: &Value, puuid: &str) -> bool {
+    ["fromId", "fromPid"].iter().any(|k| {
+        msg.get(*k)
+            .and_then(|v| v.as_str())
+            .is_some_and(|id| id.split('@').next() == Some(puuid))
+    })
+}
+
+/// Maps role names and common shorthands to the roles in [`ROLES`].
+pub fn parse_role(raw: &str) -> Option<String> {
+    let role = match raw.trim().to_lowercase().as_str() {
+        "top" => "top",
+        "jungle" | "jg" | "jgl" | "jung" => "jungle",
+        "mid" | "middle" => "mid",
+        "bot" | "bottom" | "adc" | "ad" | "carry" => "bottom",
+        "support" | "sup" | "supp" | "utility" => "support",
+        _ => return None,
+    };
+
+    Some(role.to_string())
+}
+
+/// A short chat line that starts with a role, e.g. "mid" or "jg pls".
+pub fn parse_callout(body: &str) -> Option<String> {
+    let words: Vec<&str> = body
+        .split(|c: char| c.is_whitespace() || c == ',' || c == '!' || c == '.')
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    if words.is_empty() || words.len() > 3 {
+        return None;
+    }
+
+    parse_role(words[0])
+}
+
+impl LeagueEventSubscriber for RoleResolver {
+    fn on_event(&self, event: &LeagueEvent) {
+        match event.event_type {
+            EventType::ChampSelectSession => {
+                let source = event.data.get("payload").unwrap_or(&event.data);
+                if let Ok(session) =
+                    serde_json::from_value::<ChampSelectSessionPayload>(source.clone())
+                {
+                    self.observe_session(&session);
+                }
+            }
+            EventType::ChatMessage => self.observe_chat(event),
+            EventType::GameflowSession => {
+                let source = event.data.get("payload").unwrap_or(&event.data);
+                if source.get("phase").and_then(Value::as_str) != Some("ChampSelect") {
+                    self.session.lock().unwrap().active = false;
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::domain::rune_page::RunePage;
+
+/// Stat shard rows (offense, flex, defense). Data Dragon does not list shards.
+pub const SHARD_ROWS: [[i32; 3]; 3] = [[5008, 5005, 5007], [5008, 5010, 5001], [5011, 5013, 5001]];
+
+#[derive(Debug, Clone)]
+pub struct RuneTree {
+    pub id: i32,
+    pub name: String,
+    /// Row 0 holds the keystones.
+    pub rows: Vec<Vec<i32>>,
+}
+
+impl RuneTree {
+    pub(crate) fn row_of(&self, perk: i32) -> Option<usize> {
+        self.rows.iter().position(|row| row.contains(&perk))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RuneCatalogue {
+    pub trees: Vec<RuneTree>,
+}
+
+impl RuneCatalogue {
+    pub fn tree(&self, id: i32) -> Option<&RuneTree> {
+        self.trees.iter().find(|t| t.id == id)
+    }
+}
+
+/// One problem with a rune page. `field` names the page field (and slot)
+/// the UI should highlight, `code` is stable for the UI to switch on.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuneValidationError {
+    pub field: String,
+    pub code: String,
+    pub message: String,
+}
+
+pub(crate) fn error(field: impl Into<String>, code: &str, message: impl Into<String>) -> RuneValidationError {
+    RuneValidationError {
+        field: field.into(),
+        code: code.into(),
+        message: message.into(),
+    }
+}
+
+fn pick(picks: &HashMap<i32, Option<i32>>, slot: i32) -> Option<i32> {
+    picks.get(&slot).copied().flatten()
+}
+
+fn known_tree<'a>(
+    catalogue: Option<&'a RuneCatalogue>,
+    field: &str,
+    id: Option<i32>,
+    errors: &mut Vec<RuneValidationError>,
+) -> Option<&'a RuneTree> {
+    let (catalogue, id) = (catalogue?, id?);
+    let found = catalogue.tree(id);
+    if found.is_none() {
+        errors.push(error(
+            field,
+            "unknownTree",
+            format!("Unknown rune tree {id}"),
+        ));
+    }
+    found
+}
+
+/// Checks the page against the rune trees. Without a catalogue only the
+/// structure is checked: both trees set and different, every slot filled.
+pub fn validate(page: &RunePage, catalogue: Option<&RuneCatalogue>) -> Vec<RuneValidationError> {
+    let mut errors = Vec::new();
+
+    let primary = page.primary_tree_id;
+    let secondary = page.secondary_tree_id;
+
+    if primary.is_none() {
+        errors.push(error(
+            "primary_tree_id",
+            "missingTree",
+            "Pick a primary tree",
+        ));
+    }
+    if secondary.is_none() {
+        errors.push(error(
+            "secondary_tree_id",
+            "missingTree",
+            "Pick a secondary tree",
+        ));
+    }
+    if primary.is_some() && primary == secondary {
+        errors.push(error(
+            "secondary_tree_id",
+            "sameTree",
+            "Primary and secondary trees must differ",
+        ));
+    }
+
+    let primary_tree = known_tree(catalogue, "primary_tree_id", primary, &mut errors);
+    let secondary_tree = known_tree(catalogue, "secondary_tree_id", secondary, &mut errors);
+
+    for slot in 0..4 {
+        let field = format!("primary_picks.{slot}");
+        let Some(perk) = pick(&page.primary_picks, slot) else {
+            errors.push(error(
+                field,
+                "missingPick",
+                format!("Primary row {} is empty", slot + 1),
+            ));
+            continue;
+        };
+
+        if let Some(tree) = primary_tree {
+            if tree.row_of(perk) != Some(slot as usize) {
+                errors.push(error(
+                    field,
+                    "wrongRow",
+                    format!("Rune {perk} is not in row {} of {}", slot + 1, tree.name),
+                ));
+            }
+        }
+    }
+
+    let mut secondary_rows = Vec::new();
+    for slot in 0..2 {
+        let field = format!("secondary_picks.{slot}");
+        let Some(perk) = pick(&page.secondary_picks, slot) else {
+            errors.push(error(field, "missingPick", "Pick two secondary runes"));
+            continue;
+        };
+
+        let Some(tree) = secondary_tree else {
+            continue;
+        };
+
+        match tree.row_of(perk) {
+            None => errors.push(error(
+                field,
+                "notInTree",
+                format!("Rune {perk} is not in {}", tree.name),
+            )),
+            Some(0) => errors.push(error(
+                field,
+                "keystone",
+                "Keystones cannot be taken as secondary runes",
+            )),
+            Some(row) if secondary_rows.contains(&row) => errors.push(error(
+                field,
+                "sameRow",
+                "Secondary runes must come from two different rows",
+            )),
+            Some(row) => secondary_rows.push(row),
+        }
+    }
+
+    for (slot, row) in SHARD_ROWS.iter().enumerate() {
+        let field = format!("shards.{slot}");
+        match pick(&page.shards, slot as i32) {
+            None => errors.push(error(
+                field,
+                "missingShard",
+                format!("Shard row {} is empty", slot + 1),
+            )),
+            Some(id) if !row.contains(&id) => errors.push(error(
+                field,
+                "invalidShard",
+                format!("Shard {id} is not allowed in row {}", slot + 1),
+            )),
+            Some(_) => {}
+        }
+    }
+
+    errors
+}
@@ -12,9 +12,165 @@ pub struct RunePage {
     pub secondary_tree_id: Option<i32>,
     pub secondary_picks: HashMap<i32, Option<i32>>,
     pub shards: HashMap<i32, Option<i32>>,
+    #[serde(default)]
+    pub conditions: RunePageConditions,
+    /// Among matching pages, the highest priority wins.
+    #[serde(default)]
+    pub priority: i32,
+}
+
+/// When a page applies beyond champion and role. Empty lists match anything.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RunePageConditions {
+    #[serde(default)]
+    pub queue_ids: Vec<i64>,
+    /// Champion names, any of which may be the lane opponent.
+    #[serde(default)]
+    pub lane_opponents: Vec<String>,
+    /// Data Dragon classes (e.g. `Assassin`), any of which the lane opponent has.
+    #[serde(default)]
+    pub lane_opponent_classes: Vec<String>,
+    /// Classes that must each appear on at least one ally.
+    #[serde(default)]
+    pub ally_classes: Vec<String>,
+}
+
+/// A champion in champ select, as conditions see it.
+#[derive(Debug, Clone)]
+pub struct ContextChampion {
+    pub name: String,
+    pub tags: Vec<String>,
+}
+
+/// What is known about the champ select when choosing a page.
+#[derive(Debug, Clone, Default)]
+pub struct RunePageContext {
+    pub queue_id: i64,
+    /// `None` until the enemy in our lane is revealed.
+    pub lane_opponent: Option<ContextChampion>,
+    pub allies: Vec<ContextChampion>,
+}
+
+fn has_any(list: &[String], value: &str) -> bool {
+    list.iter().any(|x| x.eq_ignore_ascii_case(value))
+}
+
+impl RunePageConditions {
+    /// How many conditions the page sets, if all of them hold in `ctx`.
+    /// Conditions on the lane opponent fail while it is unknown.
+    pub fn specificity(&self, ctx: &RunePageContext) -> Option<usize> {
+        let mut set = 0;
+
+        if !self.queue_ids.is_empty() {
+            set += 1;
+            if !self.queue_ids.contains(&ctx.queue_id) {
+                return None;
+            }
+        }
+
+        if !self.lane_opponents.is_empty() {
+            set += 1;
+            let opp = ctx.lane_opponent.as_ref()?;
+            if !has_any(&self.lane_opponents, &opp.name) {
+                return None;
+            }
+        }
+
+        if !self.lane_opponent_classes.is_empty() {
+            set += 1;
+            let opp = ctx.lane_opponent.as_ref()?;
+            if !opp
+                .tags
+                .iter()
+                .any(|t| has_any(&self.lane_opponent_classes, t))
+            {
+                return None;
+            }
+        }
+
+        if !self.ally_classes.is_empty() {
+            set += 1;
+            let present = |class: &String| {
+                ctx.allies
+                    .iter()
+                    .any(|a| a.tags.iter().any(|t| t.eq_ignore_ascii_case(class)))
+            };
+            if !self.ally_classes.iter().all(present) {
+                return None;
+            }
+        }
+
+        Some(set)
+    }
+}
+
+/// A `champions` entry or `role` that matches anything.
+pub const WILDCARD: &str = "*";
+
+/// How a page's `champions` names the champion. Later variants win.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChampionMatch {
+    /// The `*` wildcard.
+    Any,
+    /// A class tag such as `Mage`.
+    Class,
+    Exact,
+}
+
+/// How a page's `role` covers the role. Later variants win.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RoleMatch {
+    /// No role, or `*`.
+    Any,
+    Exact,
+}
+
+/// Why a page was chosen, for previews.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum PageRule {
+    Matched {
+        champion: ChampionMatch,
+        role: RoleMatch,
+    },
+    /// Nothing matched and this is the global default page.
+    Default,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunePageChoice {
+    pub page: RunePage,
+    pub rule: PageRule,
 }
 
 impl RunePage {
+    pub fn champion_match(&self, champion: &ContextChampion) -> Option<ChampionMatch> {
+        self.champions
+            .iter()
+            .filter_map(|entry| {
+                if entry.eq_ignore_ascii_case(&champion.name) {
+                    Some(ChampionMatch::Exact)
+                } else if has_any(&champion.tags, entry) {
+                    Some(ChampionMatch::Class)
+                } else if entry == WILDCARD {
+                    Some(ChampionMatch::Any)
+                } else {
+                    None
+                }
+            })
+            .max()
+    }
+
+    /// Without a role to match, every page counts as covering any role.
+    pub fn role_match(&self, role: Option<&str>) -> Option<RoleMatch> {
+        match (self.role.as_deref(), role) {
+            (None, _) | (Some(WILDCARD), _) | (_, None) => Some(RoleMatch::Any),
+            (Some(mine), Some(r)) if mine.eq_ignore_ascii_case(r) => Some(RoleMatch::Exact),
+            _ => None,
+        }
+    }
     pub fn collect_perk_ids(&self) -> Vec<i32> {
         let mut out = Vec::new();
 
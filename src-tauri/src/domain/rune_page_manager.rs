@@ -1,17 +1,73 @@
-use crate::domain::ports::LcuApiPort;
-use crate::domain::rune_page::RunePage;
+use crate::domain::client_rune_page::{ClientPageSelection, ClientRunePage};
+use crate::domain::ports::{LcuApiPort, RuneCataloguePort};
+use crate::domain::rune_catalogue::{validate, RuneValidationError};
+use crate::domain::rune_page::{
+    ContextChampion, PageRule, RunePage, RunePageChoice, RunePageContext,
+};
+use crate::domain::rune_page_transfer::{
+    encode_share_code, export_json, new_page_id, ImportConflict, ImportReport, InvalidImport,
+};
+use serde::Serialize;
 use serde_json::{json, Value};
+use std::cmp::Reverse;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tauri::Wry;
+use tokio::sync::Mutex;
 use tauri_plugin_store::Store;
 
+/// Why a page was not saved, shaped for the UI.
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum RunePageError {
+    Invalid { errors: Vec<RuneValidationError> },
+    Storage { message: String },
+}
+
+/// Pages Overseer writes to the client are named with this prefix.
+const MANAGED_PREFIX: &str = "Overseer: ";
+/// Store key holding the client id of the page Overseer last wrote.
+const MANAGED_ID_KEY: &str = "managedRunePageId";
+/// Store key holding the id of the page used when no other page matches.
+const DEFAULT_PAGE_KEY: &str = "defaultRunePageId";
+
 pub struct RunePageManager {
     store: Arc<Store<Wry>>,
+    catalogue: Arc<dyn RuneCataloguePort>,
+    /// Whether one of the user's own pages may be deleted when no slot is free.
+    replace_own: AtomicBool,
+    /// Held across `apply_page`, so concurrent session updates cannot each
+    /// create a managed page before the first one is stored.
+    applying: Mutex<()>,
 }
 
 impl RunePageManager {
-    pub fn new(store: Arc<Store<Wry>>) -> Self {
-        Self { store }
+    pub fn new(store: Arc<Store<Wry>>, catalogue: Arc<dyn RuneCataloguePort>) -> Self {
+        Self {
+            store,
+            catalogue,
+            replace_own: AtomicBool::new(false),
+            applying: Mutex::new(()),
+        }
+    }
+
+    pub fn set_replace_own_pages(&self, allowed: bool) {
+        self.replace_own.store(allowed, Ordering::Relaxed);
+    }
+
+    /// Validation errors for the page; when the rune data cannot be loaded,
+    /// only the page structure is checked.
+    pub async fn validate(&self, page: &RunePage) -> Vec<RuneValidationError> {
+        match self.catalogue.catalogue().await {
+            Ok(catalogue) => validate(page, Some(&catalogue)),
+            Err(e) => {
+                log::warn!(
+                    "RunePicker: rune data unavailable, checking structure only: {}",
+                    e
+                );
+                validate(page, None)
+            }
+        }
     }
 
     fn load_pages(&self) -> Vec<RunePage> {
@@ -34,7 +90,12 @@ impl RunePageManager {
         Ok(())
     }
 
-    pub fn save(&self, page: RunePage) -> Result<(), String> {
+    pub async fn save(&self, page: RunePage) -> Result<(), RunePageError> {
+        let errors = self.validate(&page).await;
+        if !errors.is_empty() {
+            return Err(RunePageError::Invalid { errors });
+        }
+
         let mut pages = self.load_pages();
         let id = page.id.clone();
 
@@ -45,9 +106,14 @@ impl RunePageManager {
         }
 
         self.save_pages(pages)
+            .map_err(|message| RunePageError::Storage { message })
     }
 
     pub fn delete(&self, id: &str) -> Result<(), String> {
+        if self.default_page_id().as_deref() == Some(id) {
+            self.store.delete(DEFAULT_PAGE_KEY);
+        }
+
         let pages = self
             .load_pages()
             .into_iter()
@@ -61,26 +127,215 @@ impl RunePageManager {
         self.load_pages()
     }
 
-    pub fn find_pages(&self, champion: &str, role: Option<&str>) -> Vec<RunePage> {
-        let pages = self.load_pages();
-
-        pages
+    /// Versioned JSON of the given pages, or of every page when `ids` is `None`.
+    pub fn export(&self, ids: Option<&[String]>) -> Result<String, String> {
+        let pages: Vec<RunePage> = self
+            .load_pages()
             .into_iter()
-            .filter(|p| p.champions.iter().any(|c| c == champion))
-            .filter(|p| match role {
-                Some(r) => p
-                    .role
-                    .as_deref()
-                    .map(|s| s.eq_ignore_ascii_case(r))
-                    .unwrap_or(false),
-                None => true,
+            .filter(|p| ids.is_none_or(|ids| ids.contains(&p.id)))
+            .collect();
+
+        if let Some(ids) = ids {
+            if let Some(missing) = ids.iter().find(|id| !pages.iter().any(|p| &p.id == *id)) {
+                return Err(format!("no rune page with id {missing}"));
+            }
+        }
+
+        export_json(pages)
+    }
+
+    /// Adds pages, resolving clashes with an existing id or name (case-insensitive).
+    /// Pages that do not validate are reported and left out.
+    pub async fn import(
+        &self,
+        incoming: Vec<RunePage>,
+        conflict: ImportConflict,
+    ) -> Result<ImportReport, String> {
+        let mut pages = self.load_pages();
+        let mut report = ImportReport::default();
+
+        for mut page in incoming {
+            let errors = self.validate(&page).await;
+            if !errors.is_empty() {
+                report.invalid.push(InvalidImport {
+                    name: page.name,
+                    errors,
+                });
+                continue;
+            }
+
+            let existing = pages
+                .iter()
+                .position(|p| p.id == page.id || p.name.eq_ignore_ascii_case(&page.name));
+
+            let Some(i) = existing else {
+                report.added.push(page.name.clone());
+                pages.push(page);
+                continue;
+            };
+
+            match conflict {
+                ImportConflict::Skip => report.skipped.push(page.name),
+                ImportConflict::Overwrite => {
+                    page.id = pages[i].id.clone();
+                    report.overwritten.push(page.name.clone());
+                    pages[i] = page;
+                }
+                ImportConflict::Rename => {
+                    page.id = new_page_id();
+                    page.name = Self::free_name(&pages, &page.name);
+                    report.renamed.push(page.name.clone());
+                    pages.push(page);
+                }
+            }
+        }
+
+        self.save_pages(pages)?;
+        Ok(report)
+    }
+
+    /// `name (2)`, `name (3)`, … whichever is not taken yet.
+    fn free_name(pages: &[RunePage], name: &str) -> String {
+        (2..)
+            .map(|n| format!("{name} ({n})"))
+            .find(|candidate| !pages.iter().any(|p| p.name.eq_ignore_ascii_case(candidate)))
+            .unwrap()
+    }
+
+    /// The user's pages in the client, leaving out the one Overseer manages.
+    pub async fn client_pages(&self, api: &dyn LcuApiPort) -> Result<Vec<ClientRunePage>, String> {
+        let pages = api
+            .get("/lol-perks/v1/pages")
+            .await
+            .map_err(|e| format!("could not list client rune pages: {e}"))?;
+
+        Ok(pages
+            .as_array()
+            .map(|a| {
+                a.iter()
+                    .filter_map(ClientRunePage::from_json)
+                    .collect::<Vec<_>>()
             })
-            .collect()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|p| !p.name.starts_with(MANAGED_PREFIX))
+            .collect())
+    }
+
+    /// Imports the selected client pages with the champions and role chosen
+    /// for each. Pages whose runes cannot be placed are reported as invalid.
+    pub async fn import_from_client(
+        &self,
+        api: &dyn LcuApiPort,
+        selections: Vec<ClientPageSelection>,
+        conflict: ImportConflict,
+    ) -> Result<ImportReport, String> {
+        let catalogue = self
+            .catalogue
+            .catalogue()
+            .await
+            .map_err(|e| format!("rune data unavailable: {e}"))?;
+        let client = self.client_pages(api).await?;
+
+        let mut converted = Vec::new();
+        let mut invalid = Vec::new();
+
+        for selection in selections {
+            let Some(page) = client.iter().find(|p| p.id == selection.page_id) else {
+                return Err(format!("no client rune page with id {}", selection.page_id));
+            };
+
+            match page.to_rune_page(&catalogue, selection.champions, selection.role) {
+                Ok(page) => converted.push(page),
+                Err(e) => invalid.push(InvalidImport {
+                    name: page.name.clone(),
+                    errors: vec![e],
+                }),
+            }
+        }
+
+        let mut report = self.import(converted, conflict).await?;
+        report.invalid.extend(invalid);
+        Ok(report)
+    }
+
+    pub fn share_code(&self, id: &str) -> Result<String, String> {
+        self.load_pages()
+            .iter()
+            .find(|p| p.id == id)
+            .map(encode_share_code)
+            .ok_or_else(|| format!("no rune page with id {id}"))
+    }
+
+    pub fn default_page_id(&self) -> Option<String> {
+        self.store
+            .get(DEFAULT_PAGE_KEY)
+            .and_then(|v| v.as_str().map(String::from))
+    }
+
+    /// Sets the page used when nothing else matches, or clears it with `None`.
+    pub fn set_default_page(&self, id: Option<&str>) -> Result<(), String> {
+        match id {
+            Some(id) => {
+                if !self.load_pages().iter().any(|p| p.id == id) {
+                    return Err(format!("no rune page with id {id}"));
+                }
+                self.store.set(DEFAULT_PAGE_KEY, json!(id));
+            }
+            None => {
+                self.store.delete(DEFAULT_PAGE_KEY);
+            }
+        }
+        Ok(())
     }
 
-    pub fn find_best_page(&self, champion: &str, role: Option<&str>) -> Option<RunePage> {
-        let pages = self.find_pages(champion, role);
-        pages.into_iter().next()
+    /// The page to apply, in order of precedence:
+    ///
+    /// 1. the champion by name, then by class tag, then `*`;
+    /// 2. within each, the exact role before pages for any role;
+    /// 3. then the highest priority, the most conditions, and listed first.
+    ///
+    /// Pages whose conditions do not hold are skipped. When none is left,
+    /// the global default page is used.
+    pub fn find_best_page(
+        &self,
+        champion: &ContextChampion,
+        role: Option<&str>,
+        ctx: &RunePageContext,
+    ) -> Option<RunePageChoice> {
+        let pages = self.load_pages();
+
+        let best = pages
+            .iter()
+            .enumerate()
+            .filter_map(|(i, p)| {
+                let by_champion = p.champion_match(champion)?;
+                let by_role = p.role_match(role)?;
+                let specificity = p.conditions.specificity(ctx)?;
+                Some((
+                    (by_champion, by_role, p.priority, specificity, Reverse(i)),
+                    p,
+                ))
+            })
+            .max_by_key(|(key, _)| *key)
+            .map(|((by_champion, by_role, ..), p)| RunePageChoice {
+                page: p.clone(),
+                rule: PageRule::Matched {
+                    champion: by_champion,
+                    role: by_role,
+                },
+            });
+
+        best.or_else(|| {
+            let id = self.default_page_id()?;
+            pages
+                .into_iter()
+                .find(|p| p.id == id)
+                .map(|page| RunePageChoice {
+                    page,
+                    rule: PageRule::Default,
+                })
+        })
     }
 
     async fn fetch_current(&self, api: &dyn LcuApiPort) -> Option<Value> {
@@ -132,65 +387,199 @@ impl RunePageManager {
         Self::same_perks(&want, &have)
     }
 
+    /// Pages in the client, or none when they cannot be listed.
+    async fn client_page_values(&self, api: &dyn LcuApiPort) -> Vec<Value> {
+        match api.get("/lol-perks/v1/pages").await {
+            Ok(Value::Array(pages)) => pages,
+            Ok(_) => Vec::new(),
+            Err(e) => {
+                log::warn!("RunePicker: could not list rune pages: {}", e);
+                Vec::new()
+            }
+        }
+    }
+
+    /// The page Overseer keeps for itself: the one it created last time, or
+    /// failing that an editable page named with `MANAGED_PREFIX`.
+    fn managed_page_id(&self, pages: &[Value]) -> Option<i64> {
+        let stored = self.store.get(MANAGED_ID_KEY).and_then(|v| v.as_i64());
+        let id_of = |p: &Value| p.get("id").and_then(|v| v.as_i64());
+
+        if let Some(id) = stored.filter(|id| pages.iter().any(|p| id_of(p) == Some(*id))) {
+            return Some(id);
+        }
+
+        pages
+            .iter()
+            .filter(|p| {
+                p.get("isEditable")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false)
+            })
+            .find(|p| {
+                p.get("name")
+                    .and_then(|v| v.as_str())
+                    .is_some_and(|n| n.starts_with(MANAGED_PREFIX))
+            })
+            .and_then(id_of)
+    }
+
+    /// Whether the inventory allows one more custom page. Assumes so when the
+    /// inventory cannot be read and lets the client refuse instead.
+    async fn has_free_slot(&self, pages: &[Value], api: &dyn LcuApiPort) -> bool {
+        let owned = match api.get("/lol-perks/v1/inventory").await {
+            Ok(inv) => inv.get("ownedPageCount").and_then(|v| v.as_u64()),
+            Err(e) => {
+                log::warn!("RunePicker: could not read rune page inventory: {}", e);
+                None
+            }
+        };
+
+        let Some(owned) = owned else {
+            return true;
+        };
+
+        let used = pages
+            .iter()
+            .filter(|p| {
+                p.get("isDeletable")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false)
+            })
+            .count() as u64;
+
+        used < owned
+    }
+
+    /// Frees a slot by deleting one of the user's pages, preferring the current one.
+    async fn replace_own_page(&self, pages: &[Value], api: &dyn LcuApiPort) -> bool {
+        let deletable = |p: &&Value| {
+            p.get("isDeletable")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false)
+        };
+        let is_current = |p: &&Value| p.get("current").and_then(|v| v.as_bool()).unwrap_or(false);
+
+        let victim = pages
+            .iter()
+            .filter(deletable)
+            .find(is_current)
+            .or_else(|| pages.iter().find(deletable))
+            .and_then(|p| p.get("id").and_then(|v| v.as_i64()));
+
+        let Some(id) = victim else {
+            return false;
+        };
+
+        match api.delete(&format!("/lol-perks/v1/pages/{id}")).await {
+            Ok(_) => {
+                log::info!("RunePicker: deleted rune page {} to free a slot", id);
+                true
+            }
+            Err(e) => {
+                log::warn!("RunePicker: could not delete rune page {}: {}", id, e);
+                false
+            }
+        }
+    }
+
     async fn apply_page(&self, page: RunePage, api: &dyn LcuApiPort, role: &str) {
-        let prim = page.primary_tree_id.unwrap_or(0);
-        let sec = page.secondary_tree_id.unwrap_or(0);
-        let perks = page.collect_perk_ids();
+        let _applying = self.applying.lock().await;
 
         if self.same_page(&page, api).await {
             log::info!("RunePicker: identical page already active — skipping");
             return;
         }
 
-        // 1️⃣ Fetch current page ID (same as Python)
-        let current = self.fetch_current(api).await;
-        let current_id = current
-            .as_ref()
-            .and_then(|v| v.get("id"))
-            .and_then(|v| v.as_i64());
+        let body = json!({
+            "name": format!("{MANAGED_PREFIX}{}", page.name),
+            "primaryStyleId": page.primary_tree_id.unwrap_or(0),
+            "subStyleId": page.secondary_tree_id.unwrap_or(0),
+            "selectedPerkIds": page.collect_perk_ids(),
+            "current": true
+        });
 
-        // 2️⃣ Delete current page just like Python
-        if let Some(id) = current_id {
-            let path = format!("/lol-perks/v1/pages/{id}");
-            match api.delete(&path).await {
-                Ok(_) => log::info!("RunePicker: deleted current rune page (id={})", id),
+        let pages = self.client_page_values(api).await;
+
+        if let Some(id) = self.managed_page_id(&pages) {
+            match api
+                .put(&format!("/lol-perks/v1/pages/{id}"), Some(&body))
+                .await
+            {
+                Ok(_) => {
+                    if let Err(e) = api.put("/lol-perks/v1/currentpage", Some(&json!(id))).await {
+                        log::warn!("RunePicker: could not select rune page {}: {}", id, e);
+                    }
+                    self.store.set(MANAGED_ID_KEY, json!(id));
+                    log::info!(
+                        "RunePicker: applied page '{}' ({}) to page {}",
+                        page.name,
+                        role,
+                        id
+                    );
+                    return;
+                }
                 Err(e) => log::warn!(
-                    "RunePicker: could not delete current rune page {}: {}",
+                    "RunePicker: could not update rune page {}, creating one instead: {}",
                     id,
                     e
                 ),
             }
         }
 
-        let body = json!({
-            "name": page.name,
-            "primaryStyleId": prim,
-            "subStyleId": sec,
-            "selectedPerkIds": perks,
-            "current": true
-        });
+        if !self.has_free_slot(&pages, api).await {
+            if !self.replace_own.load(Ordering::Relaxed) {
+                log::warn!(
+                    "RunePicker: no free rune page slot — free one or allow replacing your own pages"
+                );
+                return;
+            }
+
+            if !self.replace_own_page(&pages, api).await {
+                return;
+            }
+        }
 
         match api.post("/lol-perks/v1/pages", Some(&body)).await {
-            Ok(_) => log::info!("RunePicker: applied page '{}' ({})", page.name, role),
+            Ok(created) => {
+                if let Some(id) = created
+                    .as_ref()
+                    .and_then(|v| v.get("id"))
+                    .and_then(|v| v.as_i64())
+                {
+                    self.store.set(MANAGED_ID_KEY, json!(id));
+                }
+                log::info!("RunePicker: applied page '{}' ({})", page.name, role);
+            }
             Err(e) => log::error!("RunePicker: failed to create new page: {}", e),
         }
     }
 
     pub async fn apply_for_champion(
         &self,
-        name: &str,
-        _champ_id: i32,
+        champion: &ContextChampion,
         role: &str,
+        ctx: &RunePageContext,
         api: &dyn LcuApiPort,
     ) {
-        let page = match self.find_best_page(name, Some(role)) {
-            Some(p) => p,
+        let choice = match self.find_best_page(champion, Some(role), ctx) {
+            Some(c) => c,
             None => {
-                log::warn!("RunePicker: no rune page found for {name} ({role})");
+                log::warn!(
+                    "RunePicker: no rune page found for {} ({role})",
+                    champion.name
+                );
                 return;
             }
         };
 
-        self.apply_page(page, api, role).await;
+        log::info!(
+            "RunePicker: chose '{}' for {} ({role}) by {:?}",
+            choice.page.name,
+            champion.name,
+            choice.rule
+        );
+
+        self.apply_page(choice.page, api, role).await;
     }
 }
@@ -0,0 +1,169 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+use crate::domain::rune_catalogue::RuneValidationError;
+use crate::domain::rune_page::RunePage;
+
+pub const EXPORT_FORMAT: &str = "overseer-rune-pages";
+pub const EXPORT_VERSION: u32 = 1;
+
+/// Share codes read `OVR1.<base64url>`; the digit is the code version.
+const SHARE_PREFIX: &str = "OVR1.";
+
+/// A file of exported rune pages.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunePageExport {
+    pub format: String,
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub pages: Vec<RunePage>,
+}
+
+/// What to do with an imported page whose id or name is already taken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportConflict {
+    Skip,
+    Overwrite,
+    Rename,
+}
+
+/// Names of imported pages, by what happened to them.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportReport {
+    pub added: Vec<String>,
+    pub overwritten: Vec<String>,
+    pub renamed: Vec<String>,
+    pub skipped: Vec<String>,
+    pub invalid: Vec<InvalidImport>,
+}
+
+/// An imported page that was left out because it does not validate.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvalidImport {
+    pub name: String,
+    pub errors: Vec<RuneValidationError>,
+}
+
+pub fn export_json(pages: Vec<RunePage>) -> Result<String, String> {
+    let export = RunePageExport {
+        format: EXPORT_FORMAT.into(),
+        version: EXPORT_VERSION,
+        exported_at: Utc::now(),
+        pages,
+    };
+
+    serde_json::to_string_pretty(&export).map_err(|e| e.to_string())
+}
+
+pub fn parse_export(content: &str) -> Result<Vec<RunePage>, String> {
+    let export: RunePageExport =
+        serde_json::from_str(content).map_err(|e| format!("invalid rune page file: {e}"))?;
+
+    if export.format != EXPORT_FORMAT {
+        return Err(format!("not a rune page file (format '{}')", export.format));
+    }
+
+    if export.version > EXPORT_VERSION {
+        return Err(format!(
+            "rune page file version {} is newer than supported ({})",
+            export.version, EXPORT_VERSION
+        ));
+    }
+
+    Ok(export.pages)
+}
+
+/// Random id in the same UUID v4 shape the editor uses.
+pub fn new_page_id() -> String {
+    let mut b: [u8; 16] = rand::thread_rng().gen();
+    b[6] = (b[6] & 0x0f) | 0x40;
+    b[8] = (b[8] & 0x3f) | 0x80;
+
+    let hex: String = b.iter().map(|x| format!("{x:02x}")).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+fn slots(picks: &HashMap<i32, Option<i32>>, count: i32) -> Value {
+    (0..count)
+        .map(|slot| picks.get(&slot).copied().flatten())
+        .collect()
+}
+
+fn unslot(val: &Value) -> Result<HashMap<i32, Option<i32>>, String> {
+    let arr = val.as_array().ok_or("malformed share code")?;
+
+    Ok(arr
+        .iter()
+        .enumerate()
+        .map(|(slot, v)| (slot as i32, v.as_i64().map(|id| id as i32)))
+        .collect())
+}
+
+/// Encodes trees, perks, shards, champions, role and name into one line.
+pub fn encode_share_code(page: &RunePage) -> String {
+    let body = json!([
+        page.name,
+        page.role,
+        page.champions,
+        page.primary_tree_id,
+        slots(&page.primary_picks, 4),
+        page.secondary_tree_id,
+        slots(&page.secondary_picks, 2),
+        slots(&page.shards, 3),
+    ]);
+
+    format!("{SHARE_PREFIX}{}", URL_SAFE_NO_PAD.encode(body.to_string()))
+}
+
+/// Decodes a share code into a new page with a fresh id.
+pub fn decode_share_code(code: &str) -> Result<RunePage, String> {
+    let code = code.trim();
+    let encoded = code
+        .strip_prefix(SHARE_PREFIX)
+        .ok_or_else(|| match code.split_once('.') {
+            Some((prefix, _)) if prefix.starts_with("OVR") => {
+                format!("unsupported share code version '{prefix}'")
+            }
+            _ => "not an Overseer rune share code".to_string(),
+        })?;
+
+    let bytes = URL_SAFE_NO_PAD
+        .decode(encoded)
+        .map_err(|_| "malformed share code")?;
+    let body: Vec<Value> = serde_json::from_slice(&bytes).map_err(|_| "malformed share code")?;
+
+    let [name, role, champions, primary, primary_picks, secondary, secondary_picks, shards] =
+        <[Value; 8]>::try_from(body).map_err(|_| "malformed share code")?;
+
+    let tree = |v: &Value| v.as_i64().map(|id| id as i32);
+
+    Ok(RunePage {
+        id: new_page_id(),
+        name: name.as_str().unwrap_or("Shared page").to_string(),
+        champions: serde_json::from_value(champions).map_err(|_| "malformed share code")?,
+        role: role.as_str().map(str::to_string),
+        primary_tree_id: tree(&primary),
+        primary_picks: unslot(&primary_picks)?,
+        secondary_tree_id: tree(&secondary),
+        secondary_picks: unslot(&secondary_picks)?,
+        shards: unslot(&shards)?,
+        conditions: Default::default(),
+        priority: 0,
+    })
+}
@@ -0,0 +1,16 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Chroma {
+    pub id: i32,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Skin {
+    pub id: i32,
+    pub champion_id: i32,
+    pub name: String,
+    pub is_base: bool,
+    pub chromas: Vec<Chroma>,
+}
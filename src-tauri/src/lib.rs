@@ -5,9 +5,14 @@ pub mod domain;
 use crate::application::league_lifetime_manager::LeagueLifetimeManager;
 use crate::application::tauri_commands::*;
 use crate::application::dev_flag::DevFlag;
+use crate::domain::champ_select::decision_reporter::DecisionReporter;
+use crate::domain::champ_select::matchup_table::MatchupTable;
 use crate::domain::feature_manager::FeatureManager;
 use crate::domain::feature_registry::FeatureRegistry;
+use crate::domain::item_set_manager::ItemSetManager;
+use crate::domain::match_stats::{MatchStats, MatchStatsRefresher};
 use crate::domain::ports::LeagueEventPublisherPort;
+use crate::domain::role_resolver::RoleResolver;
 use crate::domain::rune_page_manager::RunePageManager;
 use std::sync::Arc;
 use tauri::Manager;
@@ -19,8 +24,15 @@ use crate::domain::league_event_publisher::LeagueEventPublisher;
 
 use crate::adapters::outbound::data_dragon_api::DataDragonApiAdapter;
 use crate::adapters::outbound::data_dragon_champion_resolver::DataDragonChampionResolver;
+use crate::adapters::outbound::data_dragon_item_catalog::DataDragonItemCatalog;
+use crate::adapters::outbound::data_dragon_rune_catalogue::DataDragonRuneCatalogue;
+use crate::adapters::outbound::dry_run_lcu_api::DryRunLcuApi;
+use crate::adapters::outbound::jsonl_champ_select_history::JsonlChampSelectHistory;
 use crate::adapters::outbound::lcu_api::LcuApiAdapter;
+use crate::adapters::outbound::lcu_match_history::LcuMatchHistory;
+use crate::adapters::outbound::lcu_skin_catalog::LcuSkinCatalog;
 use crate::adapters::outbound::tauri_log_publisher::TauriLogPublisherAdapter;
+use crate::adapters::outbound::tauri_ui_event_publisher::TauriUiEventPublisherAdapter;
 
 use crate::adapters::inbound::league_lockfile_provider::LeagueLockfileProvider;
 use crate::domain::delay_state::DelayState;
@@ -68,8 +80,35 @@ pub fn run() {
             set_feature_setting,
             list_champions,
             save_rune_page,
+            validate_rune_page,
             list_rune_pages,
+            export_rune_pages,
+            import_rune_pages,
+            get_rune_share_code,
+            import_rune_share_code,
+            list_client_rune_pages,
+            get_default_rune_page,
+            set_default_rune_page,
+            preview_rune_page,
+            import_client_rune_pages,
             delete_rune_page,
+            list_owned_skins,
+            save_item_set,
+            list_item_sets,
+            delete_item_set,
+            import_matchups,
+            list_matchups,
+            get_queue_roles,
+            set_queue_roles,
+            set_session_role,
+            get_dry_run,
+            set_dry_run,
+            list_dry_run_intents,
+            clear_dry_run_intents,
+            get_last_decision,
+            query_champ_select_history,
+            list_champion_stats,
+            refresh_match_stats,
             check_update,
             install_update,
             get_current_version
@@ -88,20 +127,50 @@ pub fn run() {
             ingame_pub.clone().start();
 
             let store = app.store("overseer-test.json")?;
-            let rpm = Arc::new(RunePageManager::new(store.clone()));
             let delay = Arc::new(DelayState::new());
             let dd_api = Arc::new(DataDragonApiAdapter::new());
+            let runes = Arc::new(DataDragonRuneCatalogue::new(dd_api.clone()));
+            let rpm = Arc::new(RunePageManager::new(store.clone(), runes));
             let resolver = DataDragonChampionResolver::new(dd_api.clone());
             let log_pub = TauriLogPublisherAdapter::new(app.handle().clone());
+            let ui_pub = TauriUiEventPublisherAdapter::new(app.handle().clone());
+            let skins = Arc::new(LcuSkinCatalog::new(lcu_api.clone()));
+            let lockfile: Arc<dyn LockfilePort> = Arc::new(LeagueLockfileProvider::new(None));
+            let items = Arc::new(DataDragonItemCatalog::new(dd_api.clone()));
+            let item_sets = Arc::new(ItemSetManager::new(store.clone(), items));
+            let matchups = Arc::new(MatchupTable::new(store.clone(), Arc::new(resolver.clone())));
+            let roles = Arc::new(RoleResolver::new(store.clone()));
+            let decisions = Arc::new(DecisionReporter::new(ui_pub.clone()));
+            let history = Arc::new(JsonlChampSelectHistory::new(app.path().app_data_dir()?));
+            league_pub.subscribe(roles.clone());
+            let match_stats = Arc::new(MatchStats::new(Arc::new(LcuMatchHistory::new(
+                lcu_api.clone(),
+            ))));
+            league_pub.subscribe(Arc::new(MatchStatsRefresher::new(match_stats.clone())));
+            tauri::async_runtime::spawn({
+                let stats = match_stats.clone();
+                async move { stats.load().await }
+            });
+            // Features only ever see the client through the dry-run switch.
+            let dry_run = Arc::new(DryRunLcuApi::new(lcu_api.clone(), store.clone()));
 
             let registry = FeatureRegistry::new(
                 league_pub.clone(),
                 ingame_pub.clone(),
                 log_pub.clone(),
-                lcu_api.clone(),
+                ui_pub.clone(),
+                dry_run.clone(),
+                lockfile.clone(),
                 dd_api.clone(),
                 delay.clone(),
                 rpm.clone(),
+                skins.clone(),
+                item_sets.clone(),
+                matchups.clone(),
+                roles.clone(),
+                decisions.clone(),
+                history.clone(),
+                match_stats.clone(),
             );
 
             let feature_manager = Arc::new(FeatureManager::new(registry, store.clone()));
@@ -114,6 +183,14 @@ pub fn run() {
             app.manage(dd_api);
             app.manage(resolver);
             app.manage(rpm);
+            app.manage(skins);
+            app.manage(item_sets);
+            app.manage(matchups);
+            app.manage(roles);
+            app.manage(dry_run);
+            app.manage(decisions);
+            app.manage(history);
+            app.manage(match_stats);
 
             Ok(())
         })
@@ -32,6 +32,10 @@ export function saveRunePage(page) {
     return invoke("save_rune_page", { page });
 }
 
+export function validateRunePage(page) {
+    return invoke("validate_rune_page", { page });
+}
+
 export function listRunePages() {
     return invoke("list_rune_pages");
 }
@@ -39,3 +43,107 @@ export function listRunePages() {
 export function deleteRunePage(id) {
     return invoke("delete_rune_page", { id });
 }
+
+export function exportRunePages(ids = null) {
+    return invoke("export_rune_pages", { ids });
+}
+
+export function importRunePages(content, conflict = "skip") {
+    return invoke("import_rune_pages", { content, conflict });
+}
+
+export function getRuneShareCode(id) {
+    return invoke("get_rune_share_code", { id });
+}
+
+export function importRuneShareCode(code, conflict = "rename") {
+    return invoke("import_rune_share_code", { code, conflict });
+}
+
+export function listClientRunePages() {
+    return invoke("list_client_rune_pages");
+}
+
+export function importClientRunePages(selections, conflict = "rename") {
+    return invoke("import_client_rune_pages", { selections, conflict });
+}
+
+export function getDefaultRunePage() {
+    return invoke("get_default_rune_page");
+}
+
+export function setDefaultRunePage(id) {
+    return invoke("set_default_rune_page", { id });
+}
+
+export function previewRunePage(champion, role = null, queueId = null) {
+    return invoke("preview_rune_page", { champion, role, queueId });
+}
+
+export function listOwnedSkins(championId) {
+    return invoke("list_owned_skins", { championId });
+}
+
+export function saveItemSet(set) {
+    return invoke("save_item_set", { set });
+}
+
+export function listItemSets() {
+    return invoke("list_item_sets");
+}
+
+export function deleteItemSet(id) {
+    return invoke("delete_item_set", { id });
+}
+
+export function importMatchups(content, format, replace) {
+    return invoke("import_matchups", { content, format, replace });
+}
+
+export function listMatchups() {
+    return invoke("list_matchups");
+}
+
+export function getQueueRoles() {
+    return invoke("get_queue_roles");
+}
+
+export function setQueueRoles(roles) {
+    return invoke("set_queue_roles", { roles });
+}
+
+export function setSessionRole(role) {
+    return invoke("set_session_role", { role });
+}
+
+export function getLastDecision() {
+    return invoke("get_last_decision");
+}
+
+export function queryChampSelectHistory(query = {}) {
+    return invoke("query_champ_select_history", { query });
+}
+
+export function listChampionStats(role = null, queueId = null) {
+    return invoke("list_champion_stats", { role, queueId });
+}
+
+export function refreshMatchStats() {
+    return invoke("refresh_match_stats");
+}
+
+export function getDryRun() {
+    return invoke("get_dry_run");
+}
+
+export function setDryRun(enabled) {
+    return invoke("set_dry_run", { enabled });
+}
+
+export function listDryRunIntents() {
+    return invoke("list_dry_run_intents");
+}
+
+export function clearDryRunIntents() {
+    return invoke("clear_dry_run_intents");
+}